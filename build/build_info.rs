@@ -1,4 +1,4 @@
-pub const OPCODES: [&str; 25] = [
+pub const OPCODES: [&str; 67] = [
     "OpReturn               = 0  | simple_instruction",
     "OpConstant             = 1  | constant_instruction",
     "OpConstantLong         = 2  | constant_long_instruction",
@@ -24,13 +24,57 @@ pub const OPCODES: [&str; 25] = [
     "OpDefineGlobal         = 22 | constant_instruction",
     "OpGetGlobal            = 23 | constant_instruction",
     "OpSetGlobal            = 24 | constant_instruction",
+    "OpGetLocal             = 25 | byte_instruction",
+    "OpSetLocal             = 26 | byte_instruction",
+    "OpJump                 = 27 | jump_instruction",
+    "OpJumpIfFalse          = 28 | jump_instruction",
+    "OpLoop                 = 29 | loop_instruction",
+    "OpCall                 = 30 | byte_instruction",
+    "OpClosure              = 31 | closure_instruction",
+    "OpGetUpvalue           = 32 | byte_instruction",
+    "OpSetUpvalue           = 33 | byte_instruction",
+    "OpCloseUpvalue         = 34 | simple_instruction",
+    "OpClass                = 35 | constant_instruction",
+    "OpGetProperty          = 36 | constant_instruction",
+    "OpSetProperty          = 37 | constant_instruction",
+    "OpMethod               = 38 | constant_instruction",
+    "OpInherit              = 39 | simple_instruction",
+    "OpGetSuper             = 40 | constant_instruction",
+    "OpCoerce               = 41 | byte_instruction",
+    "OpWrappingAdd          = 42 | simple_instruction",
+    "OpWrappingSubtract     = 43 | simple_instruction",
+    "OpWrappingMultiply     = 44 | simple_instruction",
+    "OpSaturatingAdd        = 45 | simple_instruction",
+    "OpSaturatingSubtract   = 46 | simple_instruction",
+    "OpSaturatingMultiply   = 47 | simple_instruction",
+    "OpIntegerDivide        = 48 | simple_instruction",
+    "OpModulo               = 49 | simple_instruction",
+    "OpCast                 = 50 | byte_instruction",
+    "OpBuildList            = 51 | byte_instruction",
+    "OpGetIndex             = 52 | simple_instruction",
+    "OpSetIndex             = 53 | simple_instruction",
+    "OpBuildMap             = 54 | byte_instruction",
+    "OpIterate              = 55 | simple_instruction",
+    "OpBuildTuple           = 56 | byte_instruction",
+    "OpUnpack               = 57 | byte_instruction",
+    "OpDuplicate            = 58 | byte_instruction",
+    "OpMatchTuple           = 59 | byte_instruction",
+    "OpMatchList            = 60 | byte_instruction",
+    "OpMatchVariant         = 61 | constant_byte_instruction",
+    "OpCheckEnum            = 62 | simple_instruction",
+    "OpJumpIfNull           = 63 | jump_instruction",
+    "OpCoerceNullable       = 64 | byte_instruction",
+    "OpImport               = 65 | constant_instruction",
+    "OpUpdateGlobal         = 66 | constant_byte_instruction",
 ];
 
-pub const TOKENTYPES: [&str; 54] = [
+pub const TOKENTYPES: [&str; 93] = [
     "TokenLeftParen            <=>  left parenthesis",
     "TokenRightParen           <=>  right parenthesis",
     "TokenLeftBrace            <=>  left curly brace",
     "TokenRightBrace           <=>  right curly brace",
+    "TokenLeftBracket          <=>  left square bracket",
+    "TokenRightBracket         <=>  right square bracket",
     "TokenComma                <=>  comma",
     "TokenMinus                <=>  minus",
     "TokenDot                  <=>  dot",
@@ -39,6 +83,21 @@ pub const TOKENTYPES: [&str; 54] = [
     "TokenSlash                <=>  divide",
     "TokenStar                 <=>  multiply",
     "TokenPower                <=>  power",
+    "TokenTildeSlash           <=>  integer divide",
+    "TokenPercent              <=>  modulo",
+    "TokenWrappingPlus         <=>  wrapping plus",
+    "TokenWrappingMinus        <=>  wrapping minus",
+    "TokenWrappingStar         <=>  wrapping multiply",
+    "TokenSaturatingPlus       <=>  saturating plus",
+    "TokenSaturatingMinus      <=>  saturating minus",
+    "TokenSaturatingStar       <=>  saturating multiply",
+    "TokenPlusEqual            <=>  plus equal",
+    "TokenMinusEqual           <=>  minus equal",
+    "TokenStarEqual            <=>  multiply equal",
+    "TokenSlashEqual           <=>  divide equal",
+    "TokenPowerEqual           <=>  power equal",
+    "TokenPlusPlus             <=>  increment",
+    "TokenMinusMinus           <=>  decrement",
 
     "TokenStringStart          <=>  string start",
     "TokenStringEnd            <=>  string end",
@@ -66,17 +125,30 @@ pub const TOKENTYPES: [&str; 54] = [
     "TokenTypeInt16                <=>  int16",
     "TokenTypeInt32                <=>  int32",
     "TokenTypeInt64                <=>  int64",
+    "TokenTypeUnsignedInt          <=>  uint",
+    "TokenTypeUnsignedInt8         <=>  uint8",
+    "TokenTypeUnsignedInt16        <=>  uint16",
+    "TokenTypeUnsignedInt32        <=>  uint32",
+    "TokenTypeUnsignedInt64        <=>  uint64",
 
     "TokenDeclaration          <=>  declaration",
     "TokenMut                  <=>  mut",
     "TokenColon                <=>  colon",
+    "TokenPipe                 <=>  pipe",
+    "TokenArrow                <=>  arrow",
+    "TokenQuestion             <=>  question mark",
+    "TokenQuestionQuestion     <=>  null coalesce",
+    "TokenQuestionDot          <=>  optional dot",
     "TokenAnd                  <=>  and",
+    "TokenAs                   <=>  as",
     "TokenClass                <=>  class",
     "TokenElse                 <=>  else",
+    "TokenEnum                 <=>  enum",
     "TokenFalse                <=>  false",
     "TokenFor                  <=>  for",
     "TokenFn                   <=>  function",
     "TokenIf                   <=>  if",
+    "TokenMatch                <=>  match",
     "TokenNull                 <=>  null",
     "TokenOr                   <=>  or",
     "TokenPrint                <=>  print",
@@ -84,68 +156,117 @@ pub const TOKENTYPES: [&str; 54] = [
     "TokenThis                 <=>  this",
     "TokenTrue                 <=>  true",
     "TokenWhile                <=>  while",
+    "TokenIn                   <=>  in",
+    "TokenBreak                <=>  break",
+    "TokenContinue             <=>  continue",
+    "TokenDotDot               <=>  range",
+    "TokenLabel                <=>  label",
+    "TokenSuper                <=>  super",
+    "TokenImport               <=>  import",
+    "TokenFrom                 <=>  from",
+    "TokenPub                  <=>  pub",
 
     "TokenError(String)        <=>  error",
     "TokenEof                  <=>  end of file",
 ];
 
-pub const PARSE_RULES: [&str; 52] = [
-    "LeftParen              = { grouping,      None,   PrecNone       }",
-    "RightParen             = { None,          None,   PrecNone       }",
-    "LeftBrace              = { None,          None,   PrecNone       }",
-    "RightBrace             = { None,          None,   PrecNone       }",
-    "Comma                  = { None,          None,   PrecNone       }",
-    "Minus                  = { unary,         binary, PrecTerm       }",
-    "Plus                   = { None,          binary, PrecTerm       }",
-    "Semicolon              = { None,          None,   PrecNone       }",
-    "Slash                  = { None,          binary, PrecFactor     }",
-    "Star                   = { None,          binary, PrecFactor     }",
-    "Power                  = { None,          binary, PrecFactor     }",
-    "Bang                   = { unary,         None,   PrecNone       }",
+pub const PARSE_RULES: [&str; 92] = [
+    "LeftParen              = { grouping,      call,         PrecCall       }",
+    "RightParen             = { None,          None,         PrecNone       }",
+    "LeftBrace              = { map,           None,         PrecNone       }",
+    "RightBrace             = { None,          None,         PrecNone       }",
+    "LeftBracket            = { list,          index,        PrecCall       }",
+    "RightBracket           = { None,          None,         PrecNone       }",
+    "Comma                  = { None,          None,         PrecNone       }",
+    "Dot                    = { None,          dot,          PrecCall       }",
+    "Minus                  = { unary,         binary,       PrecTerm       }",
+    "Plus                   = { None,          binary,       PrecTerm       }",
+    "Semicolon              = { None,          None,         PrecNone       }",
+    "Slash                  = { None,          binary,       PrecFactor     }",
+    "Star                   = { None,          binary,       PrecFactor     }",
+    "Power                  = { None,          binary,       PrecFactor     }",
+    "TildeSlash             = { None,          binary,       PrecFactor     }",
+    "Percent                = { None,          binary,       PrecFactor     }",
+    "WrappingPlus           = { None,          binary,       PrecTerm       }",
+    "WrappingMinus          = { None,          binary,       PrecTerm       }",
+    "WrappingStar           = { None,          binary,       PrecFactor     }",
+    "SaturatingPlus         = { None,          binary,       PrecTerm       }",
+    "SaturatingMinus        = { None,          binary,       PrecTerm       }",
+    "SaturatingStar         = { None,          binary,       PrecFactor     }",
+    "PlusEqual              = { None,          None,         PrecNone       }",
+    "MinusEqual             = { None,          None,         PrecNone       }",
+    "StarEqual              = { None,          None,         PrecNone       }",
+    "SlashEqual             = { None,          None,         PrecNone       }",
+    "PowerEqual             = { None,          None,         PrecNone       }",
+    "PlusPlus               = { None,          None,         PrecNone       }",
+    "MinusMinus             = { None,          None,         PrecNone       }",
+    "Bang                   = { unary,         None,         PrecNone       }",
 
-    "Equal                  = { None,          None,   PrecNone       }",
-    "BangEqual              = { None,          binary, PrecEquality   }",
-    "EqualEqual             = { None,          binary, PrecEquality   }",
-    "Greater                = { None,          binary, PrecComparison }",
-    "GreaterEqual           = { None,          binary, PrecComparison }",
-    "Less                   = { None,          binary, PrecComparison }",
-    "LessEqual              = { None,          binary, PrecComparison }",
+    "Equal                  = { None,          None,         PrecNone       }",
+    "BangEqual              = { None,          binary,       PrecEquality   }",
+    "EqualEqual             = { None,          binary,       PrecEquality   }",
+    "Greater                = { None,          binary,       PrecComparison }",
+    "GreaterEqual           = { None,          binary,       PrecComparison }",
+    "Less                   = { None,          binary,       PrecComparison }",
+    "LessEqual              = { None,          binary,       PrecComparison }",
 
-    "StringStart            = { string,        None,   PrecNone       }",
-    "StringEnd              = { empty,         None,   PrecNone       }",
-    "InterpolationStart     = { interpolate,   None,   PrecNone       }",
-    "InterpolationEnd       = { None,          None,   PrecNone       }",
+    "StringStart            = { string,        None,         PrecNone       }",
+    "StringEnd              = { empty,         None,         PrecNone       }",
+    "InterpolationStart     = { interpolate,   None,         PrecNone       }",
+    "InterpolationEnd       = { None,          None,         PrecNone       }",
 
-    "Identifier             = { variable,      None,   PrecNone       }",
-    "String                 = { None,          None,   PrecNone       }",
-    "Number                 = { number,        None,   PrecNone       }",
+    "Identifier             = { variable,      None,         PrecNone       }",
+    "String                 = { None,          None,         PrecNone       }",
+    "Number                 = { number,        None,         PrecNone       }",
 
-    "TypeString             = { type_keyword,  None,   PrecNone       }",
-    "TypeFloat32            = { type_keyword,  None,   PrecNone       }",
-    "TypeFloat64            = { type_keyword,  None,   PrecNone       }",
-    "TypeInt                = { type_keyword,  None,   PrecNone       }",
-    "TypeInt8               = { type_keyword,  None,   PrecNone       }",
-    "TypeInt16              = { type_keyword,  None,   PrecNone       }",
-    "TypeInt32              = { type_keyword,  None,   PrecNone       }",
-    "TypeInt64              = { type_keyword,  None,   PrecNone       }",
+    "TypeString             = { type_keyword,  None,         PrecNone       }",
+    "TypeFloat32            = { type_keyword,  None,         PrecNone       }",
+    "TypeFloat64            = { type_keyword,  None,         PrecNone       }",
+    "TypeInt                = { type_keyword,  None,         PrecNone       }",
+    "TypeInt8               = { type_keyword,  None,         PrecNone       }",
+    "TypeInt16              = { type_keyword,  None,         PrecNone       }",
+    "TypeInt32              = { type_keyword,  None,         PrecNone       }",
+    "TypeInt64              = { type_keyword,  None,         PrecNone       }",
+    "TypeUnsignedInt        = { type_keyword,  None,         PrecNone       }",
+    "TypeUnsignedInt8       = { type_keyword,  None,         PrecNone       }",
+    "TypeUnsignedInt16      = { type_keyword,  None,         PrecNone       }",
+    "TypeUnsignedInt32      = { type_keyword,  None,         PrecNone       }",
+    "TypeUnsignedInt64      = { type_keyword,  None,         PrecNone       }",
 
-    "Mutable                = { parse_next,    None,   PrecNone       }",
-    // "Public                 = { None,          None,   PrecNone       }",
-    "Declaration            = { None,          None,   PrecNone       }",
-    "Colon                  = { None,          None,   PrecNone       }",
-    "And                    = { None,          None,   PrecNone       }",
-    "Class                  = { None,          None,   PrecNone       }",
-    "Else                   = { None,          None,   PrecNone       }",
-    "False                  = { literal,       None,   PrecNone       }",
-    "For                    = { None,          None,   PrecNone       }",
-    "Fn                     = { None,          None,   PrecNone       }",
-    "If                     = { None,          None,   PrecNone       }",
-    "Null                   = { literal,       None,   PrecNone       }",
-    "Or                     = { None,          None,   PrecNone       }",
-    "Print                  = { None,          None,   PrecNone       }",
-    "Return                 = { None,          None,   PrecNone       }",
-    "This                   = { None,          None,   PrecNone       }",
-    "True                   = { literal,       None,   PrecNone       }",
-    "While                  = { None,          None,   PrecNone       }",
-    "Eof                    = { None,          None,   PrecNone       }",
+    "Mutable                = { mutable,       None,         PrecNone       }",
+    // "Public                 = { None,          None,         PrecNone       }",
+    "Declaration            = { None,          None,         PrecNone       }",
+    "Colon                  = { None,          None,         PrecNone       }",
+    "Pipe                   = { None,          None,         PrecNone       }",
+    "Arrow                  = { None,          None,         PrecNone       }",
+    "Question               = { None,          None,         PrecNone       }",
+    "QuestionQuestion       = { None,          coalesce,     PrecCoalesce   }",
+    "QuestionDot            = { None,          optional_dot, PrecCall       }",
+    "And                    = { None,          and_,         PrecAnd        }",
+    "As                     = { None,          cast,         PrecCast       }",
+    "Class                  = { None,          None,         PrecNone       }",
+    "Else                   = { None,          None,         PrecNone       }",
+    "Enum                   = { None,          None,         PrecNone       }",
+    "False                  = { literal,       None,         PrecNone       }",
+    "For                    = { None,          None,         PrecNone       }",
+    "Fn                     = { function,      None,         PrecNone       }",
+    "If                     = { None,          None,         PrecNone       }",
+    "Match                  = { match_,        None,         PrecNone       }",
+    "Null                   = { literal,       None,         PrecNone       }",
+    "Or                     = { None,          or_,          PrecOr         }",
+    "Print                  = { None,          None,         PrecNone       }",
+    "Return                 = { None,          None,         PrecNone       }",
+    "This                   = { this,          None,         PrecNone       }",
+    "True                   = { literal,       None,         PrecNone       }",
+    "While                  = { None,          None,         PrecNone       }",
+    "In                     = { None,          None,         PrecNone       }",
+    "Break                  = { None,          None,         PrecNone       }",
+    "Continue               = { None,          None,         PrecNone       }",
+    "DotDot                 = { None,          None,         PrecNone       }",
+    "Label                  = { None,          None,         PrecNone       }",
+    "Super                  = { super_,        None,         PrecNone       }",
+    "Import                 = { None,          None,         PrecNone       }",
+    "From                   = { None,          None,         PrecNone       }",
+    "Pub                    = { None,          None,         PrecNone       }",
+    "Eof                    = { None,          None,         PrecNone       }",
 ];
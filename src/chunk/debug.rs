@@ -1,5 +1,5 @@
 use super::Chunk;
-use crate::opcodes::OpCode;
+use crate::{ opcodes::OpCode, value::Value };
 
 impl Chunk {
     pub fn disassemble<T: ToString>(&self, name: T) {
@@ -24,6 +24,57 @@ impl Chunk {
         offset + 2
     }
 
+    fn byte_instruction(&self, name: &str, offset: usize) -> usize {
+        let slot = self.code[offset + 1];
+        println!("{:<16} {:4}", name, slot);
+        offset + 2
+    }
+
+    fn constant_byte_instruction(&self, name: &str, offset: usize) -> usize {
+        let constant = self.code[offset + 1];
+        let byte = self.code[offset + 2];
+        print!("{:<16} {:4} '", name, constant);
+        self.constants.print_value(constant);
+        println!("' {}", byte);
+        offset + 3
+    }
+
+    fn jump_instruction(&self, name: &str, offset: usize) -> usize {
+        let jump = ((self.code[offset + 1] as usize) << 8) | (self.code[offset + 2] as usize);
+        println!("{:<16} {:4} -> {}", name, offset, offset + 3 + jump);
+        offset + 3
+    }
+
+    fn loop_instruction(&self, name: &str, offset: usize) -> usize {
+        let jump = ((self.code[offset + 1] as usize) << 8) | (self.code[offset + 2] as usize);
+        println!("{:<16} {:4} -> {}", name, offset, offset + 3 - jump);
+        offset + 3
+    }
+
+    fn closure_instruction(&self, name: &str, offset: usize) -> usize {
+        let constant = self.code[offset + 1];
+        print!("{:<16} {:4} '", name, constant);
+        self.constants.print_value(constant);
+        println!("'");
+
+        let mut offset = offset + 2;
+        if let Value::Function(function) = self.constants.read(constant as usize) {
+            for _ in 0..function.get_upvalue_count() {
+                let is_local = self.code[offset] == 1;
+                let index = self.code[offset + 1];
+                println!(
+                    "{:04}    |                     {} {}",
+                    offset,
+                    if is_local { "local" } else { "upvalue" },
+                    index
+                );
+                offset += 2;
+            }
+        }
+
+        offset
+    }
+
     fn constant_long_instruction(&self, name: &str, offset: usize) -> usize {
         let constant =
             (self.code[offset + 1] as usize) |
@@ -71,6 +122,48 @@ impl Chunk {
             OpCode::OpDefineGlobal => self.constant_instruction("OP_DEFINE_GLOBAL", offset),
             OpCode::OpGetGlobal => self.constant_instruction("OP_GET_GLOBAL", offset),
             OpCode::OpSetGlobal => self.constant_instruction("OP_SET_GLOBAL", offset),
+            OpCode::OpGetLocal => self.byte_instruction("OP_GET_LOCAL", offset),
+            OpCode::OpSetLocal => self.byte_instruction("OP_SET_LOCAL", offset),
+            OpCode::OpJump => self.jump_instruction("OP_JUMP", offset),
+            OpCode::OpJumpIfFalse => self.jump_instruction("OP_JUMP_IF_FALSE", offset),
+            OpCode::OpLoop => self.loop_instruction("OP_LOOP", offset),
+            OpCode::OpCall => self.byte_instruction("OP_CALL", offset),
+            OpCode::OpClosure => self.closure_instruction("OP_CLOSURE", offset),
+            OpCode::OpGetUpvalue => self.byte_instruction("OP_GET_UPVALUE", offset),
+            OpCode::OpSetUpvalue => self.byte_instruction("OP_SET_UPVALUE", offset),
+            OpCode::OpCloseUpvalue => self.simple_instruction("OP_CLOSE_UPVALUE", offset),
+            OpCode::OpClass => self.constant_instruction("OP_CLASS", offset),
+            OpCode::OpGetProperty => self.constant_instruction("OP_GET_PROPERTY", offset),
+            OpCode::OpSetProperty => self.constant_instruction("OP_SET_PROPERTY", offset),
+            OpCode::OpMethod => self.constant_instruction("OP_METHOD", offset),
+            OpCode::OpInherit => self.simple_instruction("OP_INHERIT", offset),
+            OpCode::OpGetSuper => self.constant_instruction("OP_GET_SUPER", offset),
+            OpCode::OpCoerce => self.byte_instruction("OP_COERCE", offset),
+            OpCode::OpWrappingAdd => self.simple_instruction("OP_WRAPPING_ADD", offset),
+            OpCode::OpWrappingSubtract => self.simple_instruction("OP_WRAPPING_SUBTRACT", offset),
+            OpCode::OpWrappingMultiply => self.simple_instruction("OP_WRAPPING_MULTIPLY", offset),
+            OpCode::OpSaturatingAdd => self.simple_instruction("OP_SATURATING_ADD", offset),
+            OpCode::OpSaturatingSubtract => self.simple_instruction("OP_SATURATING_SUBTRACT", offset),
+            OpCode::OpSaturatingMultiply => self.simple_instruction("OP_SATURATING_MULTIPLY", offset),
+            OpCode::OpIntegerDivide => self.simple_instruction("OP_INTEGER_DIVIDE", offset),
+            OpCode::OpModulo => self.simple_instruction("OP_MODULO", offset),
+            OpCode::OpCast => self.byte_instruction("OP_CAST", offset),
+            OpCode::OpBuildList => self.byte_instruction("OP_BUILD_LIST", offset),
+            OpCode::OpGetIndex => self.simple_instruction("OP_GET_INDEX", offset),
+            OpCode::OpSetIndex => self.simple_instruction("OP_SET_INDEX", offset),
+            OpCode::OpBuildMap => self.byte_instruction("OP_BUILD_MAP", offset),
+            OpCode::OpIterate => self.simple_instruction("OP_ITERATE", offset),
+            OpCode::OpBuildTuple => self.byte_instruction("OP_BUILD_TUPLE", offset),
+            OpCode::OpUnpack => self.byte_instruction("OP_UNPACK", offset),
+            OpCode::OpDuplicate => self.byte_instruction("OP_DUPLICATE", offset),
+            OpCode::OpMatchTuple => self.byte_instruction("OP_MATCH_TUPLE", offset),
+            OpCode::OpMatchList => self.byte_instruction("OP_MATCH_LIST", offset),
+            OpCode::OpMatchVariant => self.constant_byte_instruction("OP_MATCH_VARIANT", offset),
+            OpCode::OpCheckEnum => self.simple_instruction("OP_CHECK_ENUM", offset),
+            OpCode::OpJumpIfNull => self.jump_instruction("OP_JUMP_IF_NULL", offset),
+            OpCode::OpCoerceNullable => self.byte_instruction("OP_COERCE_NULLABLE", offset),
+            OpCode::OpImport => self.constant_instruction("OP_IMPORT", offset),
+            OpCode::OpUpdateGlobal => self.constant_byte_instruction("OP_UPDATE_GLOBAL", offset),
         }
     }
 }
\ No newline at end of file
@@ -27,6 +27,10 @@ impl Chunk {
         self.constants.read(index as usize)
     }
 
+    pub fn replace_constant(&mut self, index: u16, value: Value) {
+        self.constants.replace(index as usize, value)
+    }
+
     pub fn write_byte(&mut self, byte: u8, line: usize) {
         self.code.push(byte);
         if let Some((prev_line, run_length)) = self.lines.last_mut() {
@@ -40,6 +44,10 @@ impl Chunk {
         }
     }
 
+    pub fn patch_byte(&mut self, offset: usize, byte: u8) {
+        self.code[offset] = byte;
+    }
+
     pub fn write_constant(&mut self, value: Value, line: usize) -> usize {
         let index = self.constants.write(value);
         // 0xFF = 255 and is the length of a byte
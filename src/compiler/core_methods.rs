@@ -1,5 +1,8 @@
-use super::Compiler;
+use std::rc::Rc;
+
+use super::{ ClassCompiler, Compiler, FunctionCompiler, Loop, StaticType };
 use crate::{
+    object::{ is_mutating_method, Enum, FunctionType },
     opcodes::OpCode,
     parse_rule::{ ParseRule, PARSE_RULES },
     precedence::Precedence,
@@ -34,35 +37,562 @@ impl<'a> Compiler<'a> {
         //     _ => self.statement(),
         // }
 
-        self.statement();
+        if self.is_match(&TokenClass) {
+            self.class_declaration();
+        } else if self.is_match(&TokenEnum) {
+            self.enum_declaration();
+        } else if self.is_match(&TokenImport) {
+            self.import_declaration();
+        } else if self.is_match(&TokenPub) {
+            self.export_declaration();
+        } else {
+            self.statement();
+        }
 
         if self.parser.get_panic_mode() {
             self.synchronize()
         }
     }
 
-    fn variable_declaration(&mut self, lexeme: String, value_type: ValueType, is_mutable: bool) {
-        let declaration_index = self.parse_declaration_name(lexeme, value_type, is_mutable);
+    fn export_declaration(&mut self) {
+        if self.function_compilers.len() > 1 || self.current().scope_depth > 0 {
+            self.parser.report_error(&"Only top-level declarations can be exported".to_string());
+            return;
+        }
+
+        let line = self.get_previous().get_line();
+        let export_count = self.exports.len();
+        self.is_exporting = true;
+        if self.is_match(&TokenClass) {
+            self.class_declaration();
+        } else if self.is_match(&TokenEnum) {
+            self.enum_declaration();
+        } else {
+            self.statement();
+        }
+        self.is_exporting = false;
+
+        if self.exports.len() == export_count && !self.parser.get_panic_mode() {
+            self.parser.report_error_at_line(&"Expected a declaration after 'pub'".to_string(), line);
+        }
+    }
+
+    fn class_declaration(&mut self) {
+        self.parser.consume(TokenIdentifier, "Expected class name");
+        let name = self.get_previous().get_lexeme(self.source);
+        let name_constant = self.property_name_constant(name.clone());
+
+        self.emit_bytes(OpCode::OpClass.into(), name_constant);
+        self.define_variable(name.clone(), &StaticType::dynamic(), false);
+        self.declare_static_type(&name, StaticType::new(ValueType::Class));
+
+        self.class_compilers.push(ClassCompiler::new());
+
+        if self.is_match(&TokenLess) {
+            self.parser.consume(TokenIdentifier, "Expected superclass name");
+            let superclass_name = self.get_previous().get_lexeme(self.source);
+
+            if superclass_name == name {
+                self.parser.report_error(&"A class cannot inherit from itself".to_string());
+            }
+
+            self.get_named_variable(superclass_name);
+
+            self.begin_scope();
+            self.add_local("super".to_string(), ValueType::Class, false);
+
+            self.get_named_variable(name.clone());
+            self.emit_byte(OpCode::OpInherit.into());
+
+            self.class_compilers.last_mut().unwrap().has_superclass = true;
+        }
+
+        self.get_named_variable(name);
+
+        self.parser.consume(TokenLeftBrace, "Expected '{' before class body");
+        while
+            self.get_current().get_token_type() != &TokenRightBrace &&
+            self.get_current().get_token_type() != &TokenEof
+        {
+            self.method();
+        }
+        self.parser.consume(TokenRightBrace, "Expected '}' after class body");
+
+        self.emit_byte(OpCode::OpPop.into());
+
+        if self.class_compilers.pop().unwrap().has_superclass {
+            self.end_scope();
+        }
+    }
+
+    fn enum_declaration(&mut self) {
+        self.parser.consume(TokenIdentifier, "Expected enum name");
+        let name = self.get_previous().get_lexeme(self.source);
+        let mut enumeration = Enum::new(name.clone());
+
+        self.parser.consume(TokenLeftBrace, "Expected '{' before enum variants");
+        while
+            self.get_current().get_token_type() != &TokenRightBrace &&
+            self.get_current().get_token_type() != &TokenEof
+        {
+            self.parser.consume(TokenIdentifier, "Expected variant name");
+            let variant_name = self.get_previous().get_lexeme(self.source);
+
+            let field_types = if self.is_match(&TokenLeftParen) {
+                self.variant_fields()
+            } else {
+                Vec::new()
+            };
+
+            if let Err(message) = enumeration.add_variant(variant_name, field_types) {
+                self.parser.report_error(&message);
+            }
+
+            if !self.is_match(&TokenComma) && !self.is_at_expr_end() {
+                self.parser.report_error(&"Expected ',' between enum variants".to_string());
+                break;
+            }
+        }
+        self.parser.consume(TokenRightBrace, "Expected '}' after enum variants");
+
+        let enumeration = Rc::new(enumeration);
+        self.enums.insert(name.clone(), enumeration.clone());
+        self.emit_constant(Value::Enum(enumeration), self.get_previous().get_line());
+        self.bind_variable(name, StaticType::dynamic(), false, StaticType::new(ValueType::EnumType));
+    }
+
+    fn variant_fields(&mut self) -> Vec<ValueType> {
+        let mut field_types = Vec::new();
+
+        while self.get_current().get_token_type() != &TokenRightParen {
+            if !self.get_current().get_token_type().is_type_keyword() {
+                self.parser.report_error(&"Expected field type in variant".to_string());
+                break;
+            }
+            self.parser.advance();
+
+            if field_types.len() == (u8::MAX as usize) {
+                self.parser.report_error(&"Cannot have more than 255 fields in a variant".to_string());
+            }
+            field_types.push(self.get_previous().get_token_type().parse_to_type());
+
+            if !self.is_match(&TokenComma) {
+                break;
+            }
+        }
+
+        self.parser.consume(TokenRightParen, "Expected ')' after variant fields");
+        field_types
+    }
+
+    fn method(&mut self) {
+        self.parser.consume(TokenFn, "Expected 'fn' before method");
+        self.parser.consume(TokenIdentifier, "Expected method name");
+
+        let name = self.get_previous().get_lexeme(self.source);
+        let name_constant = self.property_name_constant(name.clone());
 
+        let function_type = if name == "init" {
+            FunctionType::Initializer
+        } else {
+            FunctionType::Method
+        };
+
+        self.function_body(name, function_type);
+        self.emit_bytes(OpCode::OpMethod.into(), name_constant);
+    }
+
+    fn variable_declaration(&mut self, lexeme: String, declared_type: StaticType, is_mutable: bool) {
+        let expression_start = self.current_code_len();
         if self.is_match(&TokenDeclaration) {
             self.expression();
         } else {
-            self.emit_byte(OpCode::OpNull.into());
+            self.default_value(&lexeme, &declared_type);
         }
 
         self.consume_expr_end("Expected ';' after variable declaration");
 
-        self.define_variable(declaration_index)
+        let is_local = self.current().scope_depth > 0;
+        self.emit_type_coercion(expression_start, &declared_type, is_local);
+
+        let initializer_type = self.take_expression_type();
+        self.emit_enum_check(&declared_type, &initializer_type);
+        self.bind_variable(lexeme, declared_type, is_mutable, initializer_type);
+        self.did_declare = true;
+    }
+
+    fn default_value(&mut self, lexeme: &String, declared_type: &StaticType) {
+        let line = self.get_previous().get_line();
+        if declared_type.is_nullable() {
+            self.emit_byte(OpCode::OpNull.into());
+            self.set_expression_type(StaticType::new(ValueType::Null));
+        } else if let Some(value) = Value::default_for(declared_type.get_value_type()) {
+            self.emit_constant(value, line);
+            self.set_expression_type(declared_type.clone());
+        } else {
+            self.parser.report_error(
+                &format!("Expected ':=' after variable {}, its type has no default value", lexeme)
+            );
+        }
+    }
+
+    pub(super) fn bind_variable(
+        &mut self,
+        lexeme: String,
+        declared_type: StaticType,
+        is_mutable: bool,
+        initializer_type: StaticType
+    ) {
+        let static_type = if !declared_type.is_dynamic() {
+            declared_type.clone()
+        } else if !is_mutable {
+            initializer_type
+        } else {
+            StaticType::dynamic()
+        };
+
+        let is_local = self.current().scope_depth > 0;
+        if !is_local && !is_mutable {
+            self.immutable_globals.insert(lexeme.clone());
+        } else if !is_local {
+            self.immutable_globals.remove(&lexeme);
+        }
+
+        self.define_variable(lexeme.clone(), &declared_type, is_mutable);
+        self.declare_static_type(&lexeme, static_type);
+    }
+
+    fn multiple_variables(&mut self, first: String, first_is_mutable: bool) {
+        let mut names = vec![(first, first_is_mutable)];
+        while self.is_match(&TokenComma) {
+            let is_mutable = self.is_match(&TokenMutable);
+            self.parser.consume(TokenIdentifier, "Expected variable name after ','");
+            names.push((self.get_previous().get_lexeme(self.source), is_mutable));
+        }
+
+        if names.len() > (u8::MAX as usize) {
+            self.parser.report_error(&"Cannot bind more than 255 variables at once".to_string());
+            return;
+        }
+
+        if self.is_match(&TokenDeclaration) {
+            let value_types = self.multiple_values(names.len());
+            self.consume_expr_end("Expected ';' after variable declaration");
+
+            let mut declarations = names.into_iter().zip(value_types).collect::<Vec<_>>();
+            if self.current().scope_depth == 0 {
+                declarations.reverse();
+            }
+
+            for ((name, is_mutable), initializer_type) in declarations {
+                self.bind_variable(name, StaticType::dynamic(), is_mutable, initializer_type);
+            }
+        } else if self.is_match(&TokenEqual) {
+            if names.iter().any(|(_, is_mutable)| *is_mutable) {
+                self.parser.report_error(&"Cannot use 'mut' when reassigning variables".to_string());
+                return;
+            }
+
+            let mut targets = Vec::new();
+            for (name, _) in &names {
+                match self.assignment_target(name) {
+                    Some(target) => targets.push(target),
+                    None => {
+                        return;
+                    }
+                }
+            }
+
+            let value_types = self.multiple_values(names.len());
+            self.consume_expr_end("Expected ';' after assignment");
+
+            // The last value is on top of the stack, so the names are assigned back to front
+            for ((set_op, operand, target), value_type) in targets
+                .into_iter()
+                .zip(value_types)
+                .rev() {
+                self.check_assignment(&target, &value_type);
+                if !matches!(set_op, OpCode::OpSetGlobal) {
+                    self.emit_coercion(&target);
+                }
+                self.emit_enum_check(&target, &value_type);
+                self.emit_bytes(set_op.into(), operand);
+                self.emit_byte(OpCode::OpPop.into());
+            }
+        } else {
+            self.parser.report_error(&"Expected ':=' or '=' after variable names".to_string());
+            return;
+        }
+
+        self.did_declare = true;
+    }
+
+    fn multiple_values(&mut self, count: usize) -> Vec<StaticType> {
+        let mut value_types = Vec::new();
+        loop {
+            self.with_temporaries(value_types.len(), Self::expression);
+            value_types.push(self.take_expression_type());
+
+            if !self.is_match(&TokenComma) {
+                break;
+            }
+        }
+
+        if value_types.len() == 1 {
+            self.emit_bytes(OpCode::OpUnpack.into(), count as u8);
+            return vec![StaticType::dynamic(); count];
+        }
+
+        if value_types.len() != count {
+            self.parser.report_error(
+                &format!("Expected {} values but got {}", count, value_types.len())
+            );
+        }
+
+        value_types
     }
 
     fn statement(&mut self) {
         if self.is_match(&TokenPrint) {
             self.print_statement();
+        } else if self.is_match(&TokenIf) {
+            self.if_statement();
+        } else if self.is_match(&TokenWhile) {
+            self.while_statement(None);
+        } else if self.is_match(&TokenFor) {
+            self.for_statement(None);
+        } else if self.is_match(&TokenLabel) {
+            self.labelled_statement();
+        } else if self.is_match(&TokenBreak) {
+            self.break_statement();
+        } else if self.is_match(&TokenContinue) {
+            self.continue_statement();
+        } else if self.is_match(&TokenReturn) {
+            self.return_statement();
+        } else if self.is_match(&TokenLeftBrace) {
+            self.scoped_block();
         } else {
             self.expression_statement();
         }
     }
 
+    fn block(&mut self) {
+        while
+            self.get_current().get_token_type() != &TokenRightBrace &&
+            self.get_current().get_token_type() != &TokenEof
+        {
+            self.declaration();
+        }
+
+        self.parser.consume(TokenRightBrace, "Expected '}' after block");
+    }
+
+    pub(super) fn scoped_block(&mut self) {
+        self.begin_scope();
+        self.block();
+        self.end_scope();
+    }
+
+    fn if_statement(&mut self) {
+        self.expression();
+
+        let then_jump = self.emit_jump(OpCode::OpJumpIfFalse.into());
+        self.emit_byte(OpCode::OpPop.into());
+
+        self.parser.consume(TokenLeftBrace, "Expected '{' after if condition");
+        self.scoped_block();
+
+        let else_jump = self.emit_jump(OpCode::OpJump.into());
+
+        self.patch_jump(then_jump);
+        self.emit_byte(OpCode::OpPop.into());
+
+        if self.is_match(&TokenElse) {
+            if self.is_match(&TokenIf) {
+                self.if_statement();
+            } else {
+                self.parser.consume(TokenLeftBrace, "Expected '{' after else");
+                self.scoped_block();
+            }
+        }
+
+        self.patch_jump(else_jump);
+    }
+
+    fn labelled_statement(&mut self) {
+        let label = self.get_previous().get_lexeme(self.source)[1..].to_string();
+
+        self.parser.consume(TokenColon, "Expected ':' after loop label");
+
+        if self.is_match(&TokenWhile) {
+            self.while_statement(Some(label));
+        } else if self.is_match(&TokenFor) {
+            self.for_statement(Some(label));
+        } else {
+            self.parser.report_error(&format!("Expected a loop after label '{}", label));
+        }
+    }
+
+    fn while_statement(&mut self, label: Option<String>) {
+        let loop_start = self.current_code_len();
+        self.expression();
+
+        let exit_jump = self.emit_jump(OpCode::OpJumpIfFalse.into());
+        self.emit_byte(OpCode::OpPop.into());
+
+        let depth = self.current().scope_depth;
+        self.current_mut().loops.push(Loop::new(label, loop_start, depth));
+
+        self.parser.consume(TokenLeftBrace, "Expected '{' after while condition");
+        self.scoped_block();
+        self.emit_loop(loop_start);
+
+        self.patch_jump(exit_jump);
+        self.emit_byte(OpCode::OpPop.into());
+
+        self.end_loop();
+    }
+
+    fn for_statement(&mut self, label: Option<String>) {
+        self.begin_scope();
+
+        self.parser.consume(TokenIdentifier, "Expected loop variable after 'for'");
+        let name = self.get_previous().get_lexeme(self.source);
+
+        self.parser.consume(TokenIn, "Expected 'in' after loop variable");
+        self.expression();
+
+        let (exit_jump, increment_start) = if self.is_match(&TokenDotDot) {
+            self.with_temporaries(1, Self::expression);
+            self.for_range()
+        } else {
+            self.for_each()
+        };
+
+        let depth = self.current().scope_depth;
+        self.current_mut().loops.push(Loop::new(label, increment_start, depth));
+
+        // Every iteration declares the loop variable anew, so closures capture their own
+        self.begin_scope();
+        self.add_local(name, ValueType::Dynamic, false);
+        self.parser.consume(TokenLeftBrace, "Expected '{' after for range");
+        self.scoped_block();
+        self.end_scope();
+        self.emit_loop(increment_start);
+
+        self.patch_jump(exit_jump);
+        self.emit_byte(OpCode::OpPop.into());
+
+        self.end_loop();
+        self.end_scope();
+    }
+
+    fn for_range(&mut self) -> (usize, usize) {
+        let counter_slot = self.current().locals.len() as u8;
+        self.add_local(" counter".to_string(), ValueType::Dynamic, false);
+        self.add_local(" end".to_string(), ValueType::Dynamic, false);
+
+        let loop_start = self.current_code_len();
+        self.emit_bytes(OpCode::OpGetLocal.into(), counter_slot);
+        self.emit_bytes(OpCode::OpGetLocal.into(), counter_slot + 1);
+        self.emit_byte(OpCode::OpLess.into());
+
+        let exit_jump = self.emit_jump(OpCode::OpJumpIfFalse.into());
+        self.emit_byte(OpCode::OpPop.into());
+        let body_jump = self.emit_jump(OpCode::OpJump.into());
+
+        let increment_start = self.current_code_len();
+        self.emit_bytes(OpCode::OpGetLocal.into(), counter_slot);
+        self.emit_constant(Value::Int32(1), self.get_previous().get_line());
+        self.emit_byte(OpCode::OpAdd.into());
+        self.emit_bytes(OpCode::OpSetLocal.into(), counter_slot);
+        self.emit_byte(OpCode::OpPop.into());
+        self.emit_loop(loop_start);
+
+        self.patch_jump(body_jump);
+        self.emit_bytes(OpCode::OpGetLocal.into(), counter_slot);
+
+        (exit_jump, increment_start)
+    }
+
+    fn for_each(&mut self) -> (usize, usize) {
+        let line = self.get_previous().get_line();
+
+        self.emit_byte(OpCode::OpIterate.into());
+        self.emit_constant(Value::Int32(0), line);
+
+        let items_slot = self.current().locals.len() as u8;
+        self.add_local(" items".to_string(), ValueType::Dynamic, false);
+        self.add_local(" index".to_string(), ValueType::Dynamic, false);
+
+        let len_constant = self.property_name_constant("len".to_string());
+
+        let loop_start = self.current_code_len();
+        self.emit_bytes(OpCode::OpGetLocal.into(), items_slot + 1);
+        self.emit_bytes(OpCode::OpGetLocal.into(), items_slot);
+        self.emit_bytes(OpCode::OpGetProperty.into(), len_constant);
+        self.emit_bytes(OpCode::OpCall.into(), 0);
+        self.emit_byte(OpCode::OpLess.into());
+
+        let exit_jump = self.emit_jump(OpCode::OpJumpIfFalse.into());
+        self.emit_byte(OpCode::OpPop.into());
+        let body_jump = self.emit_jump(OpCode::OpJump.into());
+
+        let increment_start = self.current_code_len();
+        self.emit_bytes(OpCode::OpGetLocal.into(), items_slot + 1);
+        self.emit_constant(Value::Int32(1), line);
+        self.emit_byte(OpCode::OpAdd.into());
+        self.emit_bytes(OpCode::OpSetLocal.into(), items_slot + 1);
+        self.emit_byte(OpCode::OpPop.into());
+        self.emit_loop(loop_start);
+
+        self.patch_jump(body_jump);
+
+        self.emit_bytes(OpCode::OpGetLocal.into(), items_slot);
+        self.emit_bytes(OpCode::OpGetLocal.into(), items_slot + 1);
+        self.emit_byte(OpCode::OpGetIndex.into());
+
+        (exit_jump, increment_start)
+    }
+
+    fn break_statement(&mut self) {
+        if let Some(loop_index) = self.resolve_loop("break") {
+            self.discard_locals(self.current().loops[loop_index].scope_depth);
+
+            let break_jump = self.emit_jump(OpCode::OpJump.into());
+            self.current_mut().loops[loop_index].break_jumps.push(break_jump);
+        }
+
+        self.consume_expr_end("Expected ';' after break");
+    }
+
+    fn continue_statement(&mut self) {
+        if let Some(loop_index) = self.resolve_loop("continue") {
+            self.discard_locals(self.current().loops[loop_index].scope_depth);
+            self.emit_loop(self.current().loops[loop_index].continue_target);
+        }
+
+        self.consume_expr_end("Expected ';' after continue");
+    }
+
+    fn return_statement(&mut self) {
+        if self.current().function_type == FunctionType::Script {
+            self.parser.report_error(&"Cannot return from top-level code".to_string());
+            return;
+        }
+
+        if self.is_at_expr_end() {
+            self.consume_expr_end("Expected ';' after return");
+            self.emit_return();
+        } else if self.current().function_type == FunctionType::Initializer {
+            self.parser.report_error(&"Cannot return a value from an initializer".to_string());
+        } else {
+            self.expression();
+            self.consume_expr_end("Expected ';' after return value");
+            self.emit_byte(OpCode::OpReturn.into());
+        }
+    }
+
     fn print_statement(&mut self) {
         self.expression();
         self.consume_expr_end("Expected ';' after value");
@@ -70,31 +600,236 @@ impl<'a> Compiler<'a> {
     }
 
     fn expression_statement(&mut self) {
+        self.did_declare = false;
+        self.statement_start = Some(self.get_current().get_start());
         self.expression();
+
+        if self.did_declare {
+            self.did_declare = false;
+            return;
+        }
+
         self.consume_expr_end("Expected ';' after value");
         self.emit_byte(OpCode::OpPop.into());
     }
 
-    fn expression(&mut self) {
+    pub(super) fn expression(&mut self) {
         self.parse_precedence(PrecAssignment)
     }
 
-    fn named_variable(&mut self, lexeme: String, value_type: ValueType, is_mutable: bool) {
-        if self.get_can_declare() && self.get_current().get_token_type() == &TokenDeclaration {
-            self.variable_declaration(lexeme, value_type, is_mutable)
+    fn named_variable(&mut self, lexeme: String, declared_type: StaticType, is_mutable: bool) {
+        if
+            self.get_can_declare() &&
+            declared_type.is_dynamic() &&
+            self.get_current().get_token_type() == &TokenComma &&
+            self.starts_statement()
+        {
+            self.multiple_variables(lexeme, is_mutable)
+        } else if self.get_can_declare() && self.get_current().get_token_type() == &TokenDeclaration {
+            self.variable_declaration(lexeme, declared_type, is_mutable)
         } else if self.get_can_declare() && self.is_match(&TokenEqual) {
-            if !(value_type == ValueType::Dynamic || value_type == ValueType::Empty) {
+            if !declared_type.is_dynamic() {
                 self.parser.report_error_at_saved_token(
                     &format!("Unexpected type before variable {} is reassigned: ", lexeme)
                 );
-            } else {
-                let variable_index = self.identifier_lookup_constant(lexeme);
+            } else if let Some((set_op, operand, target)) = self.assignment_target(&lexeme) {
+                let expression_start = self.current_code_len();
                 self.expression();
-                self.emit_bytes(OpCode::OpSetGlobal.into(), variable_index)
+                let value_type = self.expression_type.clone();
+
+                let is_global = matches!(set_op, OpCode::OpSetGlobal);
+                self.emit_type_coercion(expression_start, &target, !is_global);
+                self.emit_enum_check(&target, &value_type);
+                self.emit_bytes(set_op.into(), operand);
+                self.set_assignment_type(target.get_value_type());
+            }
+        } else if let Some(operator) = self.match_compound_assignment() {
+            if !declared_type.is_dynamic() {
+                self.parser.report_error_at_saved_token(
+                    &format!("Unexpected type before variable {} is reassigned: ", lexeme)
+                );
+            } else if let Some((set_op, operand, target)) = self.assignment_target(&lexeme) {
+                if matches!(set_op, OpCode::OpSetGlobal) {
+                    let (opcode, result_type) = self.compound_operand(operator, target.clone());
+                    self.check_assignment(&target, &result_type);
+                    self.emit_bytes(OpCode::OpUpdateGlobal.into(), operand);
+                    self.emit_byte(opcode.into());
+                } else {
+                    let get_op = match set_op {
+                        OpCode::OpSetLocal => OpCode::OpGetLocal,
+                        _ => OpCode::OpGetUpvalue,
+                    };
+                    self.emit_bytes(get_op.into(), operand);
+
+                    let result_type = self.with_temporaries(1, |compiler| {
+                        compiler.compound_operation(operator, target.clone())
+                    });
+                    self.check_assignment(&target, &result_type);
+                    self.emit_coercion(&target);
+                    self.emit_bytes(set_op.into(), operand);
+                }
+                self.set_assignment_type(target.get_value_type());
             }
+        } else if self.get_can_declare() && !declared_type.is_dynamic() && self.is_at_expr_end() {
+            self.variable_declaration(lexeme, declared_type, is_mutable)
         } else {
-            let variable_index = self.identifier_lookup_constant(lexeme);
-            self.emit_bytes(OpCode::OpGetGlobal.into(), variable_index)
+            self.get_named_variable(lexeme)
+        }
+    }
+
+    fn match_compound_assignment(&mut self) -> Option<TokenType> {
+        let operator = self.get_current().get_token_type().clone();
+        if !self.get_can_declare() || !operator.is_compound_assignment() {
+            return None;
+        }
+
+        self.parser.advance();
+        Some(operator)
+    }
+
+    fn compound_operation(&mut self, operator: TokenType, current_type: StaticType) -> StaticType {
+        let (opcode, result_type) = self.compound_operand(operator, current_type);
+        self.emit_byte(opcode.into());
+        result_type
+    }
+
+    fn compound_operand(&mut self, operator: TokenType, current_type: StaticType) -> (OpCode, StaticType) {
+        let (binary_operator, opcode) = match operator {
+            TokenPlusEqual | TokenPlusPlus => (TokenPlus, OpCode::OpAdd),
+            TokenMinusEqual | TokenMinusMinus => (TokenMinus, OpCode::OpSubtract),
+            TokenStarEqual => (TokenStar, OpCode::OpMultiply),
+            TokenSlashEqual => (TokenSlash, OpCode::OpDivide),
+            _ => (TokenPower, OpCode::OpPower),
+        };
+
+        if matches!(operator, TokenPlusPlus | TokenMinusMinus) {
+            // The step has the type of the target where possible, so `i8` counters stay `i8`
+            let one = Value::Int32(1)
+                .coerce_to(current_type.get_value_type())
+                .unwrap_or(Value::Int32(1));
+            self.set_expression_type(StaticType::new(one.to_value_type()));
+            self.emit_constant(one, self.get_previous().get_line());
+        } else {
+            self.expression();
+        }
+
+        let operand_type = self.take_expression_type();
+        let result_type = self.check_binary(&binary_operator, current_type, operand_type);
+        (opcode, result_type)
+    }
+
+    fn assignment_target(&mut self, lexeme: &String) -> Option<(OpCode, u8, StaticType)> {
+        if let Some(slot) = self.resolve_local(lexeme) {
+            let local = &self.current().locals[slot as usize];
+            if !local.is_mutable() {
+                self.parser.report_error(&format!("Cannot reassign immutable variable: {}", lexeme));
+                return None;
+            }
+            Some((OpCode::OpSetLocal, slot, local.static_type.clone()))
+        } else if let Some(index) = self.resolve_upvalue(lexeme) {
+            let upvalue = &self.current().upvalues[index as usize];
+            if !upvalue.mutable {
+                self.parser.report_error(
+                    &format!("Cannot reassign immutable captured variable: {}", lexeme)
+                );
+                return None;
+            }
+            Some((OpCode::OpSetUpvalue, index, upvalue.static_type.clone()))
+        } else {
+            let global_type = self.global_type(lexeme);
+            let variable_index = self.identifier_lookup_constant(lexeme.clone());
+            Some((OpCode::OpSetGlobal, variable_index, global_type))
+        }
+    }
+
+    pub(super) fn get_named_variable(&mut self, lexeme: String) {
+        let (static_type, is_mutable) = if let Some(slot) = self.resolve_local(&lexeme) {
+            self.emit_bytes(OpCode::OpGetLocal.into(), slot);
+            let local = &self.current().locals[slot as usize];
+            (local.static_type.clone(), local.is_mutable())
+        } else if let Some(index) = self.resolve_upvalue(&lexeme) {
+            self.emit_bytes(OpCode::OpGetUpvalue.into(), index);
+            let upvalue = &self.current().upvalues[index as usize];
+            (upvalue.static_type.clone(), upvalue.mutable)
+        } else {
+            let static_type = self.global_type(&lexeme);
+            let is_mutable = !self.immutable_globals.contains(&lexeme);
+            let variable_index = self.identifier_lookup_constant(lexeme.clone());
+            self.emit_bytes(OpCode::OpGetGlobal.into(), variable_index);
+            (static_type, is_mutable)
+        };
+
+        self.set_expression_type(static_type);
+        self.immutable_variable = if is_mutable { None } else { Some(lexeme) };
+    }
+
+    pub fn this(&mut self) {
+        if self.class_compilers.is_empty() {
+            self.parser.report_error(&"Cannot use 'this' outside of a class".to_string());
+            return;
+        }
+
+        self.get_named_variable("this".to_string());
+        self.set_expression_type(StaticType::new(ValueType::Instance));
+    }
+
+    pub fn super_(&mut self) {
+        match self.class_compilers.last() {
+            None => {
+                self.parser.report_error(&"Cannot use 'super' outside of a class".to_string());
+                return;
+            }
+            Some(class_compiler) if !class_compiler.has_superclass => {
+                self.parser.report_error(
+                    &"Cannot use 'super' in a class with no superclass".to_string()
+                );
+                return;
+            }
+            _ => {}
+        }
+
+        self.parser.consume(TokenDot, "Expected '.' after 'super'");
+        self.parser.consume(TokenIdentifier, "Expected superclass method name");
+        let name = self.get_previous().get_lexeme(self.source);
+        let name_constant = self.property_name_constant(name);
+
+        self.get_named_variable("this".to_string());
+        self.get_named_variable("super".to_string());
+        self.emit_bytes(OpCode::OpGetSuper.into(), name_constant);
+        self.set_expression_type(StaticType::dynamic());
+    }
+
+    pub fn dot(&mut self) {
+        let receiver_type = self.take_expression_type();
+        let immutable_variable = self.immutable_variable.take();
+
+        self.parser.consume(TokenIdentifier, "Expected property name after '.'");
+        let name = self.get_previous().get_lexeme(self.source);
+
+        if let Some(variable) = immutable_variable {
+            if is_mutating_method(receiver_type.get_value_type(), &name) {
+                self.parser.report_error(
+                    &format!("Cannot modify immutable variable: {}", variable)
+                );
+                return;
+            }
+        }
+
+        let name_constant = self.property_name_constant(name);
+
+        if self.get_can_declare() && self.is_match(&TokenEqual) {
+            self.with_temporaries(1, Self::expression);
+            self.emit_bytes(OpCode::OpSetProperty.into(), name_constant);
+        } else if let Some(operator) = self.match_compound_assignment() {
+            self.emit_bytes(OpCode::OpDuplicate.into(), 1);
+            self.emit_bytes(OpCode::OpGetProperty.into(), name_constant);
+            self.with_temporaries(2, |compiler| {
+                compiler.compound_operation(operator, StaticType::dynamic())
+            });
+            self.emit_bytes(OpCode::OpSetProperty.into(), name_constant);
+            self.set_expression_type(StaticType::dynamic());
+        } else {
+            self.emit_bytes(OpCode::OpGetProperty.into(), name_constant);
         }
     }
 
@@ -109,7 +844,10 @@ impl<'a> Compiler<'a> {
 
         self.set_saved_token(self.get_previous().clone());
 
-        let parsed_type = self.get_previous().get_token_type().parse_to_type();
+        let mut parsed_type = StaticType::new(self.get_previous().get_token_type().parse_to_type());
+        if self.is_match(&TokenQuestion) {
+            parsed_type = parsed_type.nullable();
+        }
 
         match self.get_current().get_token_type() {
             TokenIdentifier => {
@@ -133,11 +871,40 @@ impl<'a> Compiler<'a> {
         }
 
         let lexeme = self.get_previous().get_lexeme(self.source);
-        self.named_variable(lexeme, ValueType::Dynamic, is_mutable)
+
+        if
+            self.get_can_declare() &&
+            matches!(self.get_current().get_token_type(), TokenIdentifier | TokenQuestion) &&
+            self.starts_statement()
+        {
+            let is_nullable = self.is_match(&TokenQuestion);
+            self.enum_variable_declaration(lexeme, is_nullable, is_mutable);
+            return;
+        }
+
+        self.named_variable(lexeme, StaticType::dynamic(), is_mutable)
+    }
+
+    fn enum_variable_declaration(&mut self, enum_name: String, is_nullable: bool, is_mutable: bool) {
+        self.parser.consume(TokenIdentifier, "Expected variable name after enum type");
+        let lexeme = self.get_previous().get_lexeme(self.source);
+
+        if self.get_current().get_token_type() != &TokenDeclaration {
+            self.parser.report_error(
+                &format!("Expected ':=' after variable {} of type {}", lexeme, enum_name)
+            );
+            return;
+        }
+
+        let mut declared_type = StaticType::enum_variant(enum_name);
+        if is_nullable {
+            declared_type = declared_type.nullable();
+        }
+        self.variable_declaration(lexeme, declared_type, is_mutable);
     }
 
     pub fn interpolate(&mut self) {
-        self.expression();
+        self.with_temporaries(1, Self::expression);
 
         self.emit_byte(OpCode::OpInterpolate.into());
 
@@ -146,7 +913,7 @@ impl<'a> Compiler<'a> {
         self.parser.advance();
 
         if !(self.get_previous().get_token_type() == &TokenStringEnd) {
-            self.string();
+            self.with_temporaries(1, Self::string);
             self.emit_byte(OpCode::OpInterpolate.into());
         }
     }
@@ -157,8 +924,8 @@ impl<'a> Compiler<'a> {
         }
 
         if self.get_previous().get_token_type() == &TokenStringEnd {
-            self.parser.advance();
             self.emit_constant(Value::String("".to_string()), self.get_previous().get_line());
+            self.set_expression_type(StaticType::new(ValueType::String));
             return;
         }
 
@@ -166,6 +933,7 @@ impl<'a> Compiler<'a> {
         if previous.get_token_type() == &TokenInterpolationStart {
             self.emit_constant(Value::String("".to_string()), previous.get_line());
             self.interpolate();
+            self.set_expression_type(StaticType::new(ValueType::String));
             return;
         }
 
@@ -173,12 +941,13 @@ impl<'a> Compiler<'a> {
 
         self.emit_constant(Value::String(string_lexeme), previous.get_line());
 
-        let new_previous = self.get_previous();
-        if new_previous.get_token_type() == &TokenStringEnd {
+        if self.get_current().get_token_type() == &TokenStringEnd {
             self.parser.advance();
         } else {
             self.expression();
         }
+
+        self.set_expression_type(StaticType::new(ValueType::String));
     }
 
     pub fn number(&mut self) {
@@ -186,20 +955,62 @@ impl<'a> Compiler<'a> {
         let line = previous.get_line();
         let lexeme = previous.get_lexeme(self.source);
 
+        let Some(value) = Self::number_value(&lexeme) else {
+            return;
+        };
+
+        self.set_expression_type(StaticType::new(value.to_value_type()));
+        self.emit_constant(value, line);
+    }
+
+    pub(super) fn number_value(lexeme: &str) -> Option<Value> {
         if let Ok(int_value) = lexeme.parse::<i32>() {
-            self.emit_constant(Value::Int32(int_value), line);
+            Some(Value::Int32(int_value))
         } else if let Ok(int_value) = lexeme.parse::<i64>() {
-            self.emit_constant(Value::Int64(int_value), line);
+            Some(Value::Int64(int_value))
         } else if let Ok(float_value) = lexeme.parse::<f64>() {
-            self.emit_constant(Value::Float64(float_value), line);
+            Some(Value::Float64(float_value))
+        } else {
+            None
         }
     }
 
     pub fn grouping(&mut self) {
+        if self.get_current().get_token_type() == &TokenRightParen {
+            self.tuple(0);
+            return;
+        }
+
         self.expression();
+
+        if self.is_match(&TokenComma) {
+            self.tuple(1);
+            return;
+        }
+
         self.parser.consume(TokenType::TokenRightParen, "Expected ')' after expression");
     }
 
+    fn tuple(&mut self, mut count: usize) {
+        while self.get_current().get_token_type() != &TokenRightParen {
+            self.with_temporaries(count, Self::expression);
+
+            if count == (u8::MAX as usize) {
+                self.parser.report_error(&"Cannot have more than 255 elements in a tuple".to_string());
+            }
+            count += 1;
+
+            if !self.is_match(&TokenComma) {
+                break;
+            }
+        }
+
+        self.parser.consume(TokenRightParen, "Expected ')' after tuple elements");
+        self.emit_bytes(OpCode::OpBuildTuple.into(), count as u8);
+        self.set_expression_type(StaticType::new(ValueType::Tuple));
+        self.immutable_variable = None;
+    }
+
     pub fn unary(&mut self) {
         let operator_type = {
             self.parser.get_previous().as_ref().unwrap().get_token_type().clone()
@@ -207,6 +1018,10 @@ impl<'a> Compiler<'a> {
 
         self.parse_precedence(PrecUnary);
 
+        let operand_type = self.take_expression_type();
+        let static_type = self.check_unary(&operator_type, operand_type);
+        self.set_expression_type(static_type);
+
         match operator_type {
             TokenType::TokenMinus => self.emit_byte(OpCode::OpNegate.into()),
             TokenType::TokenBang => self.emit_byte(OpCode::OpNot.into()),
@@ -214,14 +1029,35 @@ impl<'a> Compiler<'a> {
         }
     }
 
+    pub fn cast(&mut self) {
+        let operand_type = self.take_expression_type();
+
+        if !self.get_current().get_token_type().is_type_keyword() {
+            self.parser.report_error(&"Expected type after 'as'".to_string());
+            return;
+        }
+        self.parser.advance();
+
+        let value_type = self.get_previous().get_token_type().parse_to_type();
+        let static_type = self.check_cast(operand_type, &value_type);
+        self.set_expression_type(static_type);
+
+        self.emit_bytes(OpCode::OpCast.into(), value_type.into());
+    }
+
     pub fn binary(&mut self) {
         let operator_type = {
             self.parser.get_previous().as_ref().unwrap().get_token_type().clone()
         };
 
-        let parse_rule = self.get_rule(&operator_type);
+        let left_type = self.take_expression_type();
+        let precedence = self.get_rule(&operator_type).get_precedence().get_next();
+
+        self.with_temporaries(1, |compiler| compiler.parse_precedence(precedence));
 
-        self.parse_precedence(parse_rule.get_precedence().get_next());
+        let right_type = self.take_expression_type();
+        let static_type = self.check_binary(&operator_type, left_type, right_type);
+        self.set_expression_type(static_type);
 
         match operator_type {
             TokenBangEqual => self.emit_byte(OpCode::OpBangEqual.into()),
@@ -236,16 +1072,335 @@ impl<'a> Compiler<'a> {
             TokenStar => self.emit_byte(OpCode::OpMultiply.into()),
             TokenSlash => self.emit_byte(OpCode::OpDivide.into()),
             TokenPower => self.emit_byte(OpCode::OpPower.into()),
+            TokenTildeSlash => self.emit_byte(OpCode::OpIntegerDivide.into()),
+            TokenPercent => self.emit_byte(OpCode::OpModulo.into()),
+            TokenWrappingPlus => self.emit_byte(OpCode::OpWrappingAdd.into()),
+            TokenWrappingMinus => self.emit_byte(OpCode::OpWrappingSubtract.into()),
+            TokenWrappingStar => self.emit_byte(OpCode::OpWrappingMultiply.into()),
+            TokenSaturatingPlus => self.emit_byte(OpCode::OpSaturatingAdd.into()),
+            TokenSaturatingMinus => self.emit_byte(OpCode::OpSaturatingSubtract.into()),
+            TokenSaturatingStar => self.emit_byte(OpCode::OpSaturatingMultiply.into()),
             _ => {}
         }
     }
 
+    pub fn and_(&mut self) {
+        let left_type = self.take_expression_type();
+        let end_jump = self.emit_jump(OpCode::OpJumpIfFalse.into());
+
+        self.emit_byte(OpCode::OpPop.into());
+        self.parse_precedence(PrecAnd);
+
+        self.patch_jump(end_jump);
+        self.set_logical_type(left_type);
+    }
+
+    pub fn or_(&mut self) {
+        let left_type = self.take_expression_type();
+        let else_jump = self.emit_jump(OpCode::OpJumpIfFalse.into());
+        let end_jump = self.emit_jump(OpCode::OpJump.into());
+
+        self.patch_jump(else_jump);
+        self.emit_byte(OpCode::OpPop.into());
+        self.parse_precedence(PrecOr);
+
+        self.patch_jump(end_jump);
+        self.set_logical_type(left_type);
+    }
+
+    pub fn coalesce(&mut self) {
+        let left_type = self.take_expression_type();
+        let null_jump = self.emit_jump(OpCode::OpJumpIfNull.into());
+        let end_jump = self.emit_jump(OpCode::OpJump.into());
+
+        self.patch_jump(null_jump);
+        self.emit_byte(OpCode::OpPop.into());
+        self.parse_precedence(PrecCoalesce.get_next());
+        let right_type = self.take_expression_type();
+
+        self.patch_jump(end_jump);
+        let result_type = self.check_coalesce(left_type, right_type);
+        self.set_expression_type(result_type);
+        self.immutable_variable = None;
+    }
+
+    pub fn optional_dot(&mut self) {
+        self.take_expression_type();
+        self.immutable_variable = None;
+        let null_jump = self.emit_jump(OpCode::OpJumpIfNull.into());
+
+        self.set_can_declare(false);
+        if self.is_match(&TokenLeftParen) {
+            self.call();
+        } else {
+            self.parser.consume(TokenIdentifier, "Expected property name after '?.'");
+            let name = self.get_previous().get_lexeme(self.source);
+            let name_constant = self.property_name_constant(name);
+            self.emit_bytes(OpCode::OpGetProperty.into(), name_constant);
+        }
+
+        loop {
+            let current_ttype = self.get_current().get_token_type();
+            if (*self.get_rule(current_ttype).get_precedence() as usize) < (PrecCall as usize) {
+                break;
+            }
+            if
+                matches!(current_ttype, TokenLeftBracket | TokenLeftParen) &&
+                self.get_current().get_line() > self.get_previous().get_line()
+            {
+                break;
+            }
+            self.parser.advance();
+
+            if let Some(infix_rule) = self.get_rule(self.get_previous().get_token_type()).get_infix() {
+                infix_rule(self);
+            }
+        }
+
+        self.patch_jump(null_jump);
+        self.set_expression_type(StaticType::dynamic());
+        self.immutable_variable = None;
+    }
+
+    pub fn function(&mut self) {
+        if self.get_can_declare() && self.get_current().get_token_type() == &TokenIdentifier {
+            self.parser.advance();
+            let name = self.get_previous().get_lexeme(self.source);
+            self.function_declaration(name);
+        } else {
+            self.function_body("anonymous".to_string(), FunctionType::Function);
+        }
+    }
+
+    fn function_declaration(&mut self, name: String) {
+        if self.current().scope_depth > 0 {
+            self.add_local(name.clone(), ValueType::Function, false);
+            self.function_body(name.clone(), FunctionType::Function);
+        } else {
+            self.function_body(name.clone(), FunctionType::Function);
+            self.define_variable(name.clone(), &StaticType::dynamic(), false);
+        }
+
+        let static_type = self.take_expression_type();
+        self.declare_static_type(&name, static_type);
+        self.did_declare = true;
+    }
+
+    fn function_body(&mut self, name: String, function_type: FunctionType) {
+        self.function_compilers.push(FunctionCompiler::new(name, function_type));
+        self.begin_scope();
+
+        self.parser.consume(TokenLeftParen, "Expected '(' after function name");
+        if self.get_current().get_token_type() != &TokenRightParen {
+            loop {
+                let arity = self.current_mut().function.increment_arity();
+                if arity > (u8::MAX as usize) {
+                    self.parser.report_error(&"Cannot have more than 255 parameters".to_string());
+                }
+
+                self.parameter();
+
+                if !self.is_match(&TokenComma) {
+                    break;
+                }
+            }
+        }
+        self.parser.consume(TokenRightParen, "Expected ')' after parameters");
+
+        let arity = self.current().function.get_arity();
+        let parameter_types: Vec<StaticType> = self
+            .current()
+            .locals.iter()
+            .skip(1)
+            .take(arity)
+            .map(|local| local.static_type.clone())
+            .collect();
+
+        for (index, static_type) in parameter_types.into_iter().enumerate() {
+            let slot = index + 1;
+            if !static_type.is_dynamic() {
+                self.emit_bytes(OpCode::OpGetLocal.into(), slot as u8);
+                self.emit_coercion(&static_type);
+                self.emit_bytes(OpCode::OpSetLocal.into(), slot as u8);
+                self.emit_byte(OpCode::OpPop.into());
+            }
+        }
+
+        self.parser.consume(TokenLeftBrace, "Expected '{' before function body");
+        self.block();
+
+        let (function, upvalues) = self.end_compiler();
+        let line = self.get_previous().get_line();
+        let arity = function.get_arity();
+
+        let function_index = self.make_constant(Value::Function(Rc::new(function)), line);
+        self.emit_bytes(OpCode::OpClosure.into(), function_index);
+
+        for upvalue in upvalues {
+            self.emit_bytes(upvalue.is_local as u8, upvalue.index);
+        }
+
+        self.set_expression_type(StaticType::function(arity));
+    }
+
+    fn parameter(&mut self) {
+        let is_mutable = self.is_match(&TokenMutable);
+
+        let mut value_type = ValueType::Dynamic;
+        let mut is_nullable = false;
+        if self.get_current().get_token_type().is_type_keyword() {
+            self.parser.advance();
+            value_type = self.get_previous().get_token_type().parse_to_type();
+            is_nullable = self.is_match(&TokenQuestion);
+        }
+
+        self.parser.consume(TokenIdentifier, "Expected parameter name");
+        let name = self.get_previous().get_lexeme(self.source);
+
+        self.add_local(name, value_type.clone(), is_mutable);
+        if is_nullable {
+            if let Some(local) = self.current_mut().locals.last_mut() {
+                local.static_type = StaticType::new(value_type).nullable();
+            }
+        }
+    }
+
+    pub fn call(&mut self) {
+        let callee_type = self.take_expression_type();
+        let arg_count = self.argument_list();
+        self.check_call(&callee_type, arg_count as usize);
+        self.emit_bytes(OpCode::OpCall.into(), arg_count);
+        self.set_expression_type(StaticType::dynamic());
+        self.immutable_variable = None;
+    }
+
+    pub fn list(&mut self) {
+        let mut count: usize = 0;
+
+        while self.get_current().get_token_type() != &TokenRightBracket {
+            self.with_temporaries(count, Self::expression);
+
+            if count == (u8::MAX as usize) {
+                self.parser.report_error(&"Cannot have more than 255 elements in a list".to_string());
+            }
+            count += 1;
+
+            if !self.is_match(&TokenComma) {
+                break;
+            }
+        }
+
+        self.parser.consume(TokenRightBracket, "Expected ']' after list elements");
+        self.emit_bytes(OpCode::OpBuildList.into(), count as u8);
+        self.set_expression_type(StaticType::new(ValueType::List));
+        self.immutable_variable = None;
+    }
+
+    pub fn map(&mut self) {
+        let mut count: usize = 0;
+
+        while self.get_current().get_token_type() != &TokenRightBrace {
+            self.with_temporaries(count * 2, Self::expression);
+            self.parser.consume(TokenColon, "Expected ':' after map key");
+            self.with_temporaries(count * 2 + 1, Self::expression);
+
+            if count == (u8::MAX as usize) {
+                self.parser.report_error(&"Cannot have more than 255 entries in a map".to_string());
+            }
+            count += 1;
+
+            if !self.is_match(&TokenComma) {
+                break;
+            }
+        }
+
+        self.parser.consume(TokenRightBrace, "Expected '}' after map entries");
+        self.emit_bytes(OpCode::OpBuildMap.into(), count as u8);
+        self.set_expression_type(StaticType::new(ValueType::Map));
+        self.immutable_variable = None;
+    }
+
+    pub fn index(&mut self) {
+        let can_assign = self.get_can_declare();
+        let immutable_variable = self.immutable_variable.take();
+        let target_type = self.take_expression_type();
+
+        self.with_temporaries(1, Self::expression);
+        let index_type = self.take_expression_type();
+        self.check_index(&target_type, &index_type);
+        self.parser.consume(TokenRightBracket, "Expected ']' after index");
+        self.set_can_declare(can_assign);
+
+        if can_assign && self.is_match(&TokenEqual) {
+            if let Some(variable) = immutable_variable {
+                self.parser.report_error(
+                    &format!("Cannot modify immutable variable: {}", variable)
+                );
+            }
+
+            self.with_temporaries(2, Self::expression);
+            self.emit_byte(OpCode::OpSetIndex.into());
+            self.immutable_variable = None;
+        } else if let Some(operator) = self.match_compound_assignment() {
+            if let Some(variable) = immutable_variable {
+                self.parser.report_error(
+                    &format!("Cannot modify immutable variable: {}", variable)
+                );
+            }
+
+            self.emit_bytes(OpCode::OpDuplicate.into(), 2);
+            self.emit_byte(OpCode::OpGetIndex.into());
+            self.with_temporaries(3, |compiler| {
+                compiler.compound_operation(operator, StaticType::dynamic())
+            });
+            self.emit_byte(OpCode::OpSetIndex.into());
+            self.set_expression_type(StaticType::dynamic());
+            self.immutable_variable = None;
+        } else {
+            self.emit_byte(OpCode::OpGetIndex.into());
+            self.set_expression_type(StaticType::dynamic());
+            self.immutable_variable = immutable_variable;
+        }
+    }
+
+    fn argument_list(&mut self) -> u8 {
+        let mut arg_count: usize = 0;
+
+        if self.get_current().get_token_type() != &TokenRightParen {
+            loop {
+                self.with_temporaries(arg_count + 1, Self::expression);
+
+                if arg_count == (u8::MAX as usize) {
+                    self.parser.report_error(&"Cannot have more than 255 arguments".to_string());
+                }
+                arg_count += 1;
+
+                if !self.is_match(&TokenComma) {
+                    break;
+                }
+            }
+        }
+
+        self.parser.consume(TokenRightParen, "Expected ')' after arguments");
+
+        arg_count as u8
+    }
+
     pub fn literal(&mut self) {
         let previous_ttype = self.get_previous().get_token_type();
         match previous_ttype {
-            TokenFalse => self.emit_byte(OpCode::OpFalse.into()),
-            TokenTrue => self.emit_byte(OpCode::OpTrue.into()),
-            TokenNull => self.emit_byte(OpCode::OpNull.into()),
+            TokenFalse => {
+                self.emit_byte(OpCode::OpFalse.into());
+                self.set_expression_type(StaticType::new(ValueType::Bool));
+            }
+            TokenTrue => {
+                self.emit_byte(OpCode::OpTrue.into());
+                self.set_expression_type(StaticType::new(ValueType::Bool));
+            }
+            TokenNull => {
+                self.emit_byte(OpCode::OpNull.into());
+                self.set_expression_type(StaticType::new(ValueType::Null));
+            }
             _ => {}
         }
     }
@@ -260,8 +1415,14 @@ impl<'a> Compiler<'a> {
         if let Some(prefix_rule) = prefix_rule {
             let can_declare = precedence <= PrecAssignment;
             self.set_can_declare(can_declare);
+            self.set_expression_type(StaticType::dynamic());
+            self.immutable_variable = None;
             prefix_rule(self);
 
+            if self.did_declare {
+                return;
+            }
+
             loop {
                 let current_ttype = self.get_current().get_token_type();
                 let current_precedence = self.get_rule(current_ttype).get_precedence();
@@ -269,6 +1430,15 @@ impl<'a> Compiler<'a> {
                 if (*current_precedence as usize) < (precedence as usize) {
                     break;
                 }
+
+                // A '[' or '(' on a new line starts a list or tuple literal, rather than indexing
+                // or calling the line before
+                if
+                    matches!(current_ttype, TokenLeftBracket | TokenLeftParen) &&
+                    self.get_current().get_line() > self.get_previous().get_line()
+                {
+                    break;
+                }
                 self.parser.advance();
 
                 let infix_rule = self.get_rule(self.get_previous().get_token_type()).get_infix();
@@ -277,7 +1447,11 @@ impl<'a> Compiler<'a> {
                     infix_rule(self);
                 }
 
-                if can_declare && (self.is_match(&TokenDeclaration) || self.is_match(&TokenEqual)) {
+                let is_assignment =
+                    self.is_match(&TokenDeclaration) ||
+                    self.is_match(&TokenEqual) ||
+                    self.get_current().get_token_type().is_compound_assignment();
+                if can_declare && is_assignment {
                     self.parser.report_error(&format!("Invalid assignment target"));
                 }
             }
@@ -291,7 +1465,11 @@ impl<'a> Compiler<'a> {
         PARSE_RULES.get(token_type).unwrap()
     }
 
-    pub fn parse_next(&mut self) {
+    pub fn mutable(&mut self) {
+        if self.statement_start == Some(self.get_previous().get_start()) {
+            self.statement_start = Some(self.get_current().get_start());
+        }
+
         self.parse_precedence(PrecAssignment)
     }
 
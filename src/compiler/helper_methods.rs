@@ -1,17 +1,19 @@
 use crate::{
+    chunk::Chunk,
+    object::FunctionType,
     opcodes::OpCode,
     token::{ token_type::TokenType, Token },
-    value::{ Value, ValueType, Variable, VariableDefinition },
+    value::{ Value, ValueType, VariableDefinition },
 };
-use super::Compiler;
+use super::{ Compiler, FunctionCompiler, Local, StaticType, UpvalueReference };
 
 impl<'a> Compiler<'a> {
     fn write_chunk(&mut self, byte: u8, line: usize) {
-        self.compiling_chunk.write_byte(byte, line);
+        self.current_chunk().write_byte(byte, line);
     }
 
-    fn make_constant(&mut self, value: Value, line: usize) -> u8 {
-        let constant = self.compiling_chunk.write_constant(value, line);
+    pub(super) fn make_constant(&mut self, value: Value, line: usize) -> u8 {
+        let constant = self.current_chunk().write_constant(value, line);
 
         // let constant = self.add_constant(current_chunk, value);
         if constant > 255 {
@@ -29,23 +31,322 @@ impl<'a> Compiler<'a> {
         constant
     }
 
+    pub(super) fn property_name_constant(&mut self, name: String) -> u8 {
+        self.make_constant(Value::String(name), self.get_previous().get_line())
+    }
+
     pub(super) fn parse_declaration_name(
         &mut self,
         lexeme: String,
         value_type: ValueType,
+        is_nullable: bool,
         is_mutable: bool
     ) -> u8 {
+        let definition = VariableDefinition::new(lexeme, value_type, is_nullable, is_mutable);
         let constant = self.make_constant(
-            Value::VariableDefinition(VariableDefinition::new(lexeme, value_type, is_mutable)),
+            Value::VariableDefinition(definition),
             self.get_previous().get_line()
         );
         constant
     }
 
-    pub(super) fn define_variable(&mut self, global_variable_index: u8) {
+    pub(super) fn define_variable(
+        &mut self,
+        lexeme: String,
+        declared_type: &StaticType,
+        is_mutable: bool
+    ) {
+        let value_type = declared_type.get_value_type().clone();
+        if self.current().scope_depth > 0 {
+            self.add_local(lexeme, value_type, is_mutable);
+            return;
+        }
+
+        if self.is_exporting && self.function_compilers.len() == 1 {
+            self.exports.push(lexeme.clone());
+        }
+
+        let global_variable_index = self.parse_declaration_name(
+            lexeme,
+            value_type,
+            declared_type.is_nullable(),
+            is_mutable
+        );
         self.emit_bytes(OpCode::OpDefineGlobal.into(), global_variable_index);
     }
 
+    pub(super) fn emit_type_coercion(
+        &mut self,
+        expression_start: usize,
+        declared_type: &StaticType,
+        check_at_runtime: bool
+    ) {
+        let value_type = declared_type.get_value_type();
+        if value_type.is_dynamic() {
+            return;
+        }
+
+        let chunk = self.current_chunk();
+        let literal = match &chunk.get_code()[expression_start..] {
+            [op, index] if matches!(OpCode::from(*op), OpCode::OpConstant) =>
+                Some((Some(*index), chunk.read_constant(*index as u16))),
+            [op] =>
+                match OpCode::from(*op) {
+                    OpCode::OpTrue => Some((None, Value::Bool(true))),
+                    OpCode::OpFalse => Some((None, Value::Bool(false))),
+                    OpCode::OpNull => Some((None, Value::Null)),
+                    _ => None,
+                }
+            _ => None,
+        };
+
+        if let Some((constant, value)) = literal {
+            let value = if declared_type.is_nullable() {
+                value.coerce_to_nullable(value_type)
+            } else {
+                value.coerce_to(value_type)
+            };
+
+            match value {
+                Ok(value) => {
+                    if let Some(index) = constant {
+                        self.current_chunk().replace_constant(index as u16, value);
+                    }
+                }
+                Err(message) => self.parser.report_error(&message),
+            }
+        } else {
+            let expression_type = self.expression_type.clone();
+            self.check_assignment(declared_type, &expression_type);
+
+            if check_at_runtime {
+                self.emit_coercion(declared_type);
+            }
+        }
+    }
+
+    pub(super) fn emit_coercion(&mut self, declared_type: &StaticType) {
+        let value_type = declared_type.get_value_type().clone();
+        if value_type.is_dynamic() {
+            return;
+        }
+
+        let opcode = if declared_type.is_nullable() {
+            OpCode::OpCoerceNullable
+        } else {
+            OpCode::OpCoerce
+        };
+        self.emit_bytes(opcode.into(), value_type.into());
+    }
+
+    pub(super) fn emit_enum_check(&mut self, target: &StaticType, value: &StaticType) {
+        let Some(enum_name) = target.get_enum_name().cloned() else {
+            return;
+        };
+
+        self.check_enum_assignment(&enum_name, value);
+        let null_jump = target.is_nullable().then(|| self.emit_jump(OpCode::OpJumpIfNull.into()));
+
+        self.emit_enum_value(enum_name);
+        self.emit_byte(OpCode::OpCheckEnum.into());
+
+        if let Some(null_jump) = null_jump {
+            self.patch_jump(null_jump);
+        }
+    }
+
+    pub(super) fn emit_enum_value(&mut self, enum_name: String) {
+        let expression_type = self.take_expression_type();
+        let immutable_variable = self.immutable_variable.take();
+
+        self.get_named_variable(enum_name);
+
+        self.set_expression_type(expression_type);
+        self.immutable_variable = immutable_variable;
+    }
+
+    pub(super) fn add_local(&mut self, name: String, value_type: ValueType, is_mutable: bool) {
+        if self.current().locals.len() > (u8::MAX as usize) {
+            self.parser.report_error(&"Too many local variables in scope".to_string());
+            return;
+        }
+
+        let depth = self.current().scope_depth;
+        self.current_mut().locals.push(Local::new(name, depth, value_type, is_mutable));
+    }
+
+    pub(super) fn with_temporaries<T>(
+        &mut self,
+        count: usize,
+        compile: impl FnOnce(&mut Self) -> T
+    ) -> T {
+        let locals = self.current().locals.len();
+        let depth = self.current().scope_depth;
+        for _ in 0..count {
+            let temporary = Local::new(" temporary".to_string(), depth, ValueType::Dynamic, false);
+            self.current_mut().locals.push(temporary);
+        }
+
+        let result = compile(self);
+        self.current_mut().locals.truncate(locals);
+        result
+    }
+
+    pub(super) fn resolve_local(&self, name: &String) -> Option<u8> {
+        self.resolve_local_in(self.function_compilers.len() - 1, name)
+    }
+
+    fn resolve_local_in(&self, compiler_index: usize, name: &String) -> Option<u8> {
+        self.function_compilers[compiler_index].locals
+            .iter()
+            .rposition(|local| local.get_name() == name)
+            .map(|index| index as u8)
+    }
+
+    pub(super) fn resolve_upvalue(&mut self, name: &String) -> Option<u8> {
+        self.resolve_upvalue_in(self.function_compilers.len() - 1, name)
+    }
+
+    fn resolve_upvalue_in(&mut self, compiler_index: usize, name: &String) -> Option<u8> {
+        if compiler_index == 0 {
+            return None;
+        }
+        let enclosing_index = compiler_index - 1;
+
+        if let Some(local_index) = self.resolve_local_in(enclosing_index, name) {
+            let local = &mut self.function_compilers[enclosing_index].locals[local_index as usize];
+            local.is_captured = true;
+
+            let upvalue = UpvalueReference::new(
+                local_index,
+                true,
+                local.get_value_type(),
+                local.static_type.clone(),
+                local.is_mutable()
+            );
+            return Some(self.add_upvalue(compiler_index, upvalue));
+        }
+
+        if let Some(upvalue_index) = self.resolve_upvalue_in(enclosing_index, name) {
+            let enclosing_upvalue =
+                &self.function_compilers[enclosing_index].upvalues[upvalue_index as usize];
+
+            let upvalue = UpvalueReference::new(
+                upvalue_index,
+                false,
+                enclosing_upvalue.value_type.clone(),
+                enclosing_upvalue.static_type.clone(),
+                enclosing_upvalue.mutable
+            );
+            return Some(self.add_upvalue(compiler_index, upvalue));
+        }
+
+        None
+    }
+
+    fn add_upvalue(&mut self, compiler_index: usize, upvalue: UpvalueReference) -> u8 {
+        let upvalues = &self.function_compilers[compiler_index].upvalues;
+
+        if let Some(existing) = upvalues.iter().position(|u| u == &upvalue) {
+            return existing as u8;
+        }
+
+        if upvalues.len() > (u8::MAX as usize) {
+            self.parser.report_error(&"Too many closure variables in function".to_string());
+            return 0;
+        }
+
+        self.function_compilers[compiler_index].upvalues.push(upvalue);
+        (self.function_compilers[compiler_index].upvalues.len() - 1) as u8
+    }
+
+    pub(super) fn resolve_loop(&mut self, keyword: &str) -> Option<usize> {
+        if self.current().loops.is_empty() {
+            self.parser.report_error(&format!("Cannot use '{}' outside of a loop", keyword));
+            return None;
+        }
+
+        if !self.is_match(&TokenType::TokenLabel) {
+            return Some(self.current().loops.len() - 1);
+        }
+
+        let label = self.get_previous().get_lexeme(self.source)[1..].to_string();
+        let loop_index = self
+            .current()
+            .loops.iter()
+            .rposition(|l| l.label.as_ref() == Some(&label));
+
+        if loop_index.is_none() {
+            self.parser.report_error(&format!("Unknown loop label '{}", label));
+        }
+
+        loop_index
+    }
+
+    pub(super) fn end_loop(&mut self) {
+        if let Some(ended_loop) = self.current_mut().loops.pop() {
+            for break_jump in ended_loop.break_jumps {
+                self.patch_jump(break_jump);
+            }
+        }
+    }
+
+    pub(super) fn discard_locals(&mut self, depth: usize) {
+        let captured = self
+            .current()
+            .locals.iter()
+            .rev()
+            .take_while(|local| local.get_depth() > depth)
+            .map(|local| local.is_captured)
+            .collect::<Vec<bool>>();
+
+        for is_captured in captured {
+            self.emit_pop_local(is_captured);
+        }
+    }
+
+    pub(super) fn begin_scope(&mut self) {
+        self.current_mut().scope_depth += 1;
+    }
+
+    pub(super) fn end_scope(&mut self) {
+        self.current_mut().scope_depth -= 1;
+
+        let depth = self.current().scope_depth;
+        while let Some(local) = self.current().locals.last() {
+            if local.get_depth() <= depth {
+                break;
+            }
+
+            let is_captured = local.is_captured;
+            self.emit_pop_local(is_captured);
+            self.current_mut().locals.pop();
+        }
+    }
+
+    fn emit_pop_local(&mut self, is_captured: bool) {
+        if is_captured {
+            self.emit_byte(OpCode::OpCloseUpvalue.into());
+        } else {
+            self.emit_byte(OpCode::OpPop.into());
+        }
+    }
+
+    pub(super) fn starts_statement(&self) -> bool {
+        self.statement_start == Some(self.get_previous().get_start())
+    }
+
+    pub(super) fn is_at_expr_end(&self) -> bool {
+        let current = self.get_current();
+
+        match current.get_token_type() {
+            | TokenType::TokenSemicolon
+            | TokenType::TokenRightBrace
+            | TokenType::TokenEof => true,
+            _ => current.get_line() > self.get_previous().get_line(),
+        }
+    }
+
     pub(super) fn consume_expr_end(&mut self, message: &str) {
         let current = self.get_current();
 
@@ -53,7 +354,7 @@ impl<'a> Compiler<'a> {
             TokenType::TokenSemicolon => {
                 self.parser.advance();
             }
-            TokenType::TokenEof => {}
+            TokenType::TokenEof | TokenType::TokenRightBrace => {}
             _ => {
                 let prev_line = self.get_previous().get_line();
 
@@ -74,12 +375,17 @@ impl<'a> Compiler<'a> {
 
             match self.get_current().get_token_type() {
                 | TokenType::TokenClass
+                | TokenType::TokenEnum
                 | TokenType::TokenFn
                 | TokenType::TokenFor
                 | TokenType::TokenIf
                 | TokenType::TokenWhile
+                | TokenType::TokenBreak
+                | TokenType::TokenContinue
                 | TokenType::TokenPrint
-                | TokenType::TokenReturn => {
+                | TokenType::TokenReturn
+                | TokenType::TokenImport
+                | TokenType::TokenPub => {
                     return;
                 }
                 _ => self.parser.advance(),
@@ -117,10 +423,62 @@ impl<'a> Compiler<'a> {
         self.emit_bytes(OpCode::OpConstant.into(), constant)
     }
 
+    pub(super) fn emit_jump(&mut self, instruction: u8) -> usize {
+        self.emit_byte(instruction);
+        self.emit_bytes(0xff, 0xff);
+        self.current_chunk().get_code().len() - 2
+    }
+
+    pub(super) fn emit_loop(&mut self, loop_start: usize) {
+        self.emit_byte(OpCode::OpLoop.into());
+
+        // +2 to also jump over the operand of the loop instruction
+        let offset = self.current_chunk().get_code().len() - loop_start + 2;
+        if offset > (u16::MAX as usize) {
+            self.parser.report_error(&"Loop body too large".to_string());
+        }
+
+        self.emit_bytes(((offset >> 8) & 0xff) as u8, (offset & 0xff) as u8);
+    }
+
+    pub(super) fn patch_jump(&mut self, offset: usize) {
+        // -2 to adjust for the bytecode of the jump offset itself
+        let jump = self.current_chunk().get_code().len() - offset - 2;
+
+        if jump > (u16::MAX as usize) {
+            self.parser.report_error(&"Too much code to jump over".to_string());
+            return;
+        }
+
+        self.current_chunk().patch_byte(offset, ((jump >> 8) & 0xff) as u8);
+        self.current_chunk().patch_byte(offset + 1, (jump & 0xff) as u8);
+    }
+
     pub(super) fn emit_return(&mut self) {
+        if self.current().function_type == FunctionType::Initializer {
+            self.emit_bytes(OpCode::OpGetLocal.into(), 0);
+        } else {
+            self.emit_byte(OpCode::OpNull.into());
+        }
         self.emit_byte(OpCode::OpReturn.into());
     }
 
+    pub(super) fn current(&self) -> &FunctionCompiler {
+        self.function_compilers.last().unwrap()
+    }
+
+    pub(super) fn current_mut(&mut self) -> &mut FunctionCompiler {
+        self.function_compilers.last_mut().unwrap()
+    }
+
+    pub(super) fn current_chunk(&mut self) -> &mut Chunk {
+        self.current_mut().function.get_chunk_mut()
+    }
+
+    pub(super) fn current_code_len(&mut self) -> usize {
+        self.current_chunk().get_code().len()
+    }
+
     pub(super) fn get_double_previous_type(&self) -> Option<&TokenType> {
         self.parser.get_double_previous_type().as_ref()
     }
@@ -1,38 +1,219 @@
 mod helper_methods;
 mod core_methods;
+mod pattern;
+mod type_checker;
+mod modules;
 
-use crate::{ token::token_type::TokenType, chunk::Chunk, parser::Parser };
+use std::{ cell::RefCell, collections::{ HashMap, HashSet }, path::PathBuf, rc::Rc };
+
+pub use modules::ModuleCache;
+
+use crate::{
+    token::token_type::TokenType,
+    parser::Parser,
+    value::{ StaticType, ValueType },
+    object::{ Enum, Function, FunctionType },
+};
+
+#[derive(Debug)]
+pub struct Local {
+    name: String,
+    depth: usize,
+    value_type: ValueType,
+    static_type: StaticType,
+    mutable: bool,
+    is_captured: bool,
+}
+
+impl Local {
+    pub fn new(name: String, depth: usize, value_type: ValueType, mutable: bool) -> Self {
+        let static_type = StaticType::new(value_type.clone());
+        Self { name, depth, value_type, static_type, mutable, is_captured: false }
+    }
+
+    pub fn get_name(&self) -> &String {
+        &self.name
+    }
+
+    pub fn get_depth(&self) -> usize {
+        self.depth
+    }
+
+    pub fn get_value_type(&self) -> ValueType {
+        self.value_type.clone()
+    }
+
+    pub fn is_mutable(&self) -> bool {
+        self.mutable
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct UpvalueReference {
+    index: u8,
+    is_local: bool,
+    value_type: ValueType,
+    static_type: StaticType,
+    mutable: bool,
+}
+
+impl UpvalueReference {
+    pub fn new(
+        index: u8,
+        is_local: bool,
+        value_type: ValueType,
+        static_type: StaticType,
+        mutable: bool
+    ) -> Self {
+        Self { index, is_local, value_type, static_type, mutable }
+    }
+}
+
+#[derive(Debug)]
+pub struct Loop {
+    label: Option<String>,
+    continue_target: usize,
+    scope_depth: usize,
+    break_jumps: Vec<usize>,
+}
+
+impl Loop {
+    pub fn new(label: Option<String>, continue_target: usize, scope_depth: usize) -> Self {
+        Self { label, continue_target, scope_depth, break_jumps: Vec::new() }
+    }
+}
+
+#[derive(Debug)]
+pub struct FunctionCompiler {
+    function: Function,
+    function_type: FunctionType,
+    locals: Vec<Local>,
+    upvalues: Vec<UpvalueReference>,
+    scope_depth: usize,
+    loops: Vec<Loop>,
+}
+
+impl FunctionCompiler {
+    pub fn new(name: String, function_type: FunctionType) -> Self {
+        let mut locals = Vec::with_capacity(256);
+
+        // Slot zero holds the function being called, or the receiver for methods
+        let slot_zero_name = match function_type {
+            FunctionType::Method | FunctionType::Initializer => "this",
+            _ => "",
+        };
+        locals.push(Local::new(slot_zero_name.to_string(), 0, ValueType::Dynamic, false));
+
+        Self {
+            function: Function::new(name),
+            function_type,
+            locals,
+            upvalues: Vec::new(),
+            scope_depth: 0,
+            loops: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ClassCompiler {
+    has_superclass: bool,
+}
+
+impl ClassCompiler {
+    pub fn new() -> Self {
+        Self { has_superclass: false }
+    }
+}
 
 pub struct Compiler<'a> {
     parser: Parser<'a>,
     source: &'a str,
-    compiling_chunk: &'a mut Chunk,
+    function_compilers: Vec<FunctionCompiler>,
+    class_compilers: Vec<ClassCompiler>,
+    did_declare: bool,
+    type_checking: bool,
+    expression_type: StaticType,
+    global_types: HashMap<String, StaticType>,
+    unresolved_globals: Vec<(String, usize)>,
+    immutable_variable: Option<String>,
+    immutable_globals: HashSet<String>,
+    statement_start: Option<usize>,
+    enums: HashMap<String, Rc<Enum>>,
+    path: Option<PathBuf>,
+    module_cache: Rc<RefCell<ModuleCache>>,
+    exports: Vec<String>,
+    is_exporting: bool,
 }
 
 impl<'a> Compiler<'a> {
-    pub fn new(source: &'a str, chunk: &'a mut Chunk) -> Self {
+    pub fn new(source: &'a str) -> Self {
         let parser = Parser::new(source);
 
-        Self { parser, source, compiling_chunk: chunk }
+        Self {
+            parser,
+            source,
+            function_compilers: vec![FunctionCompiler::new("script".to_string(), FunctionType::Script)],
+            class_compilers: Vec::new(),
+            did_declare: false,
+            type_checking: false,
+            expression_type: StaticType::dynamic(),
+            global_types: HashMap::new(),
+            unresolved_globals: Vec::new(),
+            immutable_variable: None,
+            immutable_globals: HashSet::new(),
+            statement_start: None,
+            enums: HashMap::new(),
+            path: None,
+            module_cache: Rc::new(RefCell::new(ModuleCache::new())),
+            exports: Vec::new(),
+            is_exporting: false,
+        }
+    }
+
+    pub fn declare_immutable_global(&mut self, name: String) {
+        self.immutable_globals.insert(name);
     }
 
-    pub fn compile(&mut self) -> bool {
+    pub fn compile(&mut self) -> Option<Function> {
+        if let Some(path) = &self.path {
+            self.module_cache.borrow_mut().begin_loading(path.clone());
+        }
+
         while !self.is_match(&TokenType::TokenEof) {
             self.declaration();
         }
 
         self.parser.consume(TokenType::TokenEof, "Expected end of expression");
-        self.end_compiler();
+        self.check_unresolved_globals();
+        let (function, _) = self.end_compiler();
 
-        self.parser.get_had_error()
+        if self.path.is_some() {
+            self.module_cache.borrow_mut().end_loading();
+        }
+
+        if self.parser.get_had_error() {
+            None
+        } else {
+            Some(function)
+        }
     }
 
-    pub fn end_compiler(&mut self) {
+    pub fn end_compiler(&mut self) -> (Function, Vec<UpvalueReference>) {
         self.emit_return();
 
+        let mut function_compiler = self.function_compilers.pop().unwrap();
+        function_compiler.function.set_upvalue_count(function_compiler.upvalues.len());
+        if let Some(file) = self.parser.get_file() {
+            function_compiler.function.set_file(file.clone());
+        }
+
         #[cfg(any(feature = "debug_trace_execution", feature = "debug_print_code"))]
         if !self.parser.get_had_error() {
-            self.compiling_chunk.disassemble("code");
+            let function = &function_compiler.function;
+            function.get_chunk().disassemble(function.get_name());
         }
+
+        (function_compiler.function, function_compiler.upvalues)
     }
 }
@@ -0,0 +1,191 @@
+use std::{ cell::RefCell, collections::HashMap, fs, path::{ Path, PathBuf }, rc::Rc };
+
+use super::{ Compiler, FunctionCompiler, StaticType };
+use crate::{
+    object::{ Function, FunctionType },
+    opcodes::OpCode,
+    token::token_type::TokenType,
+    value::{ Value, ValueType },
+};
+use TokenType::*;
+
+#[derive(Debug, Clone)]
+struct CompiledModule {
+    function: Rc<Function>,
+    exports: Vec<(String, StaticType)>,
+}
+
+#[derive(Debug)]
+pub struct ModuleCache {
+    compiled: HashMap<PathBuf, CompiledModule>,
+    loading: Vec<PathBuf>,
+    prelude: HashMap<String, StaticType>,
+}
+
+impl ModuleCache {
+    pub fn new() -> Self {
+        Self { compiled: HashMap::new(), loading: Vec::new(), prelude: HashMap::new() }
+    }
+
+    pub fn declare_prelude(&mut self, name: String, static_type: StaticType) {
+        self.prelude.insert(name, static_type);
+    }
+
+    pub(super) fn begin_loading(&mut self, path: PathBuf) {
+        self.loading.push(path);
+    }
+
+    pub(super) fn end_loading(&mut self) {
+        self.loading.pop();
+    }
+
+    fn circular_chain(&self, path: &Path) -> Option<String> {
+        if !self.loading.iter().any(|loading| loading == path) {
+            return None;
+        }
+
+        let chain = self.loading
+            .iter()
+            .map(|loading| loading.as_path())
+            .chain([path])
+            .map(display_path)
+            .collect::<Vec<String>>();
+        Some(chain.join(" -> "))
+    }
+}
+
+fn display_path(path: &Path) -> String {
+    let relative = std::env::current_dir()
+        .ok()
+        .and_then(|dir| path.strip_prefix(dir).ok().map(Path::to_path_buf));
+    relative.as_deref().unwrap_or(path).display().to_string()
+}
+
+impl<'a> Compiler<'a> {
+    fn new_module(source: &'a str, path: PathBuf, module_cache: Rc<RefCell<ModuleCache>>) -> Self {
+        let mut compiler = Self::new(source);
+        compiler.function_compilers = vec![
+            FunctionCompiler::new(path.display().to_string(), FunctionType::Script)
+        ];
+        compiler.parser.set_file(display_path(&path));
+        compiler.path = Some(path);
+        compiler.module_cache = module_cache;
+        compiler
+    }
+
+    pub fn set_module_cache(&mut self, module_cache: Rc<RefCell<ModuleCache>>) {
+        self.module_cache = module_cache;
+    }
+
+    pub fn set_path(&mut self, path: PathBuf) {
+        self.path = Some(path);
+    }
+
+    pub(super) fn import_declaration(&mut self) {
+        let binding = if self.is_match(&TokenIdentifier) {
+            let name = self.get_previous().get_lexeme(self.source);
+            self.parser.consume(TokenFrom, "Expected 'from' after module name");
+            Some(name)
+        } else {
+            None
+        };
+
+        self.parser.consume(TokenStringStart, "Expected module path");
+        self.parser.consume(TokenString, "Expected module path");
+        let import_path = self.get_previous().get_lexeme_string(self.source);
+        self.parser.consume(TokenStringEnd, "Module paths cannot be interpolated");
+        if self.parser.get_panic_mode() {
+            return;
+        }
+
+        if self.function_compilers.len() > 1 || self.current().scope_depth > 0 {
+            self.report_import_error("Modules can only be imported at the top level".to_string());
+            return;
+        }
+
+        let Some(module) = self.load_module(&import_path) else {
+            return;
+        };
+
+        let line = self.get_previous().get_line();
+        let constant = self.make_constant(Value::Function(module.function), line);
+        self.emit_bytes(OpCode::OpImport.into(), constant);
+
+        match binding {
+            Some(name) => {
+                self.bind_variable(name, StaticType::dynamic(), false, StaticType::new(ValueType::Module));
+            }
+            None => {
+                for (name, static_type) in module.exports {
+                    self.emit_bytes(OpCode::OpDuplicate.into(), 1);
+                    let name_constant = self.property_name_constant(name.clone());
+                    self.emit_bytes(OpCode::OpGetProperty.into(), name_constant);
+                    self.bind_variable(name, StaticType::dynamic(), false, static_type);
+                }
+                self.emit_byte(OpCode::OpPop.into());
+            }
+        }
+    }
+
+    fn report_import_error(&mut self, message: String) {
+        let line = self.get_previous().get_line();
+        self.parser.report_error_at_line(&message, line);
+    }
+
+    fn load_module(&mut self, import_path: &str) -> Option<CompiledModule> {
+        let base = self.path
+            .as_ref()
+            .and_then(|path| path.parent())
+            .map(Path::to_path_buf)
+            .unwrap_or_default();
+        let Ok(path) = base.join(import_path).canonicalize() else {
+            self.report_import_error(format!("Cannot find module '{}'", import_path));
+            return None;
+        };
+
+        if let Some(module) = self.module_cache.borrow().compiled.get(&path) {
+            return Some(module.clone());
+        }
+
+        let circular_chain = self.module_cache.borrow().circular_chain(&path);
+        if let Some(chain) = circular_chain {
+            self.report_import_error(format!("Circular import: {}", chain));
+            return None;
+        }
+
+        let source = match fs::read_to_string(&path) {
+            Ok(source) => source,
+            Err(error) => {
+                self.report_import_error(format!("Cannot read module '{}': {}", import_path, error));
+                return None;
+            }
+        };
+
+        let mut compiler = Compiler::new_module(&source, path.clone(), self.module_cache.clone());
+        compiler.set_type_checking(self.type_checking);
+        for (name, static_type) in self.module_cache.borrow().prelude.iter() {
+            compiler.declare_immutable_global(name.clone());
+            if self.type_checking {
+                compiler.declare_global_type(name.clone(), static_type.clone());
+            }
+        }
+
+        let Some(mut function) = compiler.compile() else {
+            self.parser.set_had_error();
+            return None;
+        };
+
+        let exports = compiler.exports
+            .iter()
+            .map(|name| {
+                let static_type = compiler.global_types.get(name).cloned();
+                (name.clone(), static_type.unwrap_or(StaticType::dynamic()))
+            })
+            .collect();
+        function.set_exports(compiler.exports.clone());
+
+        let module = CompiledModule { function: Rc::new(function), exports };
+        self.module_cache.borrow_mut().compiled.insert(path, module.clone());
+        Some(module)
+    }
+}
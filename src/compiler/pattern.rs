@@ -0,0 +1,452 @@
+use super::{ Compiler, StaticType };
+use crate::{ opcodes::OpCode, token::token_type::TokenType, value::{ Value, ValueType } };
+use TokenType::*;
+
+#[derive(Debug)]
+enum Pattern {
+    Wildcard,
+    Binding(String),
+    Literal(Value),
+    Tuple(Vec<Pattern>),
+    List(Vec<Pattern>),
+    Variant(String, String, Vec<Pattern>),
+    Alternatives(Vec<Pattern>),
+}
+
+impl Pattern {
+    fn is_irrefutable(&self) -> bool {
+        match self {
+            Pattern::Wildcard | Pattern::Binding(_) => true,
+            Pattern::Alternatives(alternatives) => alternatives.iter().any(Pattern::is_irrefutable),
+            _ => false,
+        }
+    }
+
+    fn matches_literal(&self, value: &Value) -> bool {
+        match self {
+            Pattern::Literal(literal) => literal == value,
+            Pattern::Alternatives(alternatives) =>
+                alternatives.iter().any(|alternative| alternative.matches_literal(value)),
+            _ => false,
+        }
+    }
+
+    fn covered_variants(&self, covered: &mut Vec<(String, String)>) {
+        match self {
+            Pattern::Variant(enum_name, variant, fields) if fields.iter().all(Pattern::is_irrefutable) => {
+                covered.push((enum_name.clone(), variant.clone()));
+            }
+            Pattern::Alternatives(alternatives) => {
+                for alternative in alternatives {
+                    alternative.covered_variants(covered);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn bindings(&self, path: &mut Vec<u8>, bindings: &mut Vec<(String, Vec<u8>)>) {
+        match self {
+            Pattern::Binding(name) => bindings.push((name.clone(), path.clone())),
+            Pattern::Tuple(elements) | Pattern::List(elements) | Pattern::Variant(_, _, elements) => {
+                for (index, element) in elements.iter().enumerate() {
+                    path.push(index as u8);
+                    element.bindings(path, bindings);
+                    path.pop();
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl<'a> Compiler<'a> {
+    pub fn match_(&mut self) {
+        self.expression();
+        let value_type = self.take_expression_type();
+
+        self.begin_scope();
+        let value_slot = self.current().locals.len() as u8;
+        self.add_local(" match".to_string(), ValueType::Dynamic, false);
+
+        self.parser.consume(TokenLeftBrace, "Expected '{' after match value");
+
+        let mut end_jumps = Vec::new();
+        let mut is_exhaustive = false;
+        let mut matched_booleans = (false, false);
+        let mut covered_variants = Vec::new();
+
+        while
+            self.get_current().get_token_type() != &TokenRightBrace &&
+            self.get_current().get_token_type() != &TokenEof
+        {
+            let pattern = self.pattern();
+            let has_guard = self.is_match(&TokenIf);
+
+            if !has_guard {
+                is_exhaustive |= pattern.is_irrefutable();
+                matched_booleans.0 |= pattern.matches_literal(&Value::Bool(true));
+                matched_booleans.1 |= pattern.matches_literal(&Value::Bool(false));
+                pattern.covered_variants(&mut covered_variants);
+            }
+
+            end_jumps.push(self.match_arm(pattern, has_guard, value_slot));
+
+            if self.parser.get_panic_mode() {
+                break;
+            }
+        }
+
+        self.parser.consume(TokenRightBrace, "Expected '}' after match arms");
+
+        if value_type.get_value_type() == &ValueType::Bool && matched_booleans == (true, true) {
+            is_exhaustive = true;
+        }
+
+        if !is_exhaustive {
+            if let Some(enum_name) = self.covered_enum(&covered_variants) {
+                self.emit_bytes(OpCode::OpGetLocal.into(), value_slot);
+                self.emit_enum_value(enum_name);
+                self.emit_byte(OpCode::OpCheckEnum.into());
+                self.emit_byte(OpCode::OpPop.into());
+                is_exhaustive = true;
+            }
+        }
+
+        if !is_exhaustive {
+            self.parser.report_error(&"Match is not exhaustive, add a '_' arm".to_string());
+        }
+
+        for end_jump in end_jumps {
+            self.patch_jump(end_jump);
+        }
+
+        // The result has taken the place of the matched value, so it stays on the stack
+        self.current_mut().locals.pop();
+        self.current_mut().scope_depth -= 1;
+
+        self.set_expression_type(StaticType::dynamic());
+        self.immutable_variable = None;
+    }
+
+    fn covered_enum(&self, covered_variants: &[(String, String)]) -> Option<String> {
+        covered_variants
+            .iter()
+            .filter_map(|(enum_name, _)| self.enums.get(enum_name))
+            .find(|enumeration| {
+                enumeration.get_variants().iter().all(|variant| {
+                    covered_variants
+                        .iter()
+                        .any(|(enum_name, name)| {
+                            enum_name == enumeration.get_name() && name == variant.get_name()
+                        })
+                })
+            })
+            .map(|enumeration| enumeration.get_name().clone())
+    }
+
+    fn match_arm(&mut self, pattern: Pattern, has_guard: bool, value_slot: u8) -> usize {
+        let match_depth = self.current().scope_depth;
+
+        let mut fail_jumps = Vec::new();
+        self.pattern_test(&pattern, value_slot, &mut Vec::new(), &mut fail_jumps);
+
+        self.begin_scope();
+        let mut bindings = Vec::new();
+        pattern.bindings(&mut Vec::new(), &mut bindings);
+        for (index, (name, path)) in bindings.iter().enumerate() {
+            if bindings[..index].iter().any(|(other, _)| other == name) {
+                self.parser.report_error(&format!("Variable {} is bound twice in pattern", name));
+            }
+
+            self.emit_pattern_value(value_slot, path);
+            self.add_local(name.clone(), ValueType::Dynamic, false);
+        }
+
+        let mut guard_fail_jump = None;
+        if has_guard {
+            self.expression();
+
+            let fail_jump = self.emit_jump(OpCode::OpJumpIfFalse.into());
+            self.emit_byte(OpCode::OpPop.into());
+            let pass_jump = self.emit_jump(OpCode::OpJump.into());
+
+            self.patch_jump(fail_jump);
+            self.emit_byte(OpCode::OpPop.into());
+            self.discard_locals(match_depth);
+            guard_fail_jump = Some(self.emit_jump(OpCode::OpJump.into()));
+
+            self.patch_jump(pass_jump);
+        }
+
+        self.parser.consume(TokenArrow, "Expected '=>' after match pattern");
+        let is_block = self.is_match(&TokenLeftBrace);
+        if is_block {
+            self.scoped_block();
+            self.emit_byte(OpCode::OpNull.into());
+        } else {
+            self.did_declare = false;
+            self.expression();
+
+            if self.did_declare {
+                self.did_declare = false;
+                self.parser.report_error(
+                    &"Cannot declare a variable in a match arm outside of a block".to_string()
+                );
+            }
+        }
+
+        self.emit_bytes(OpCode::OpSetLocal.into(), value_slot);
+        self.emit_byte(OpCode::OpPop.into());
+        self.end_scope();
+        let end_jump = self.emit_jump(OpCode::OpJump.into());
+
+        if !fail_jumps.is_empty() {
+            for fail_jump in fail_jumps {
+                self.patch_jump(fail_jump);
+            }
+            self.emit_byte(OpCode::OpPop.into());
+        }
+
+        if let Some(guard_fail_jump) = guard_fail_jump {
+            self.patch_jump(guard_fail_jump);
+        }
+
+        if !self.is_match(&TokenComma) && !is_block && !self.is_at_expr_end() {
+            self.parser.report_error(&"Expected ',' between match arms".to_string());
+        }
+
+        end_jump
+    }
+
+    fn pattern_test(
+        &mut self,
+        pattern: &Pattern,
+        value_slot: u8,
+        path: &mut Vec<u8>,
+        fail_jumps: &mut Vec<usize>
+    ) {
+        match pattern {
+            Pattern::Wildcard | Pattern::Binding(_) => {}
+            Pattern::Literal(value) => {
+                self.emit_pattern_value(value_slot, path);
+                self.emit_constant(value.clone(), self.get_previous().get_line());
+                self.emit_byte(OpCode::OpEqualEqual.into());
+                self.emit_pattern_check(fail_jumps);
+            }
+            Pattern::Tuple(elements) | Pattern::List(elements) => {
+                let opcode = if matches!(pattern, Pattern::Tuple(_)) {
+                    OpCode::OpMatchTuple
+                } else {
+                    OpCode::OpMatchList
+                };
+
+                self.emit_pattern_value(value_slot, path);
+                self.emit_bytes(opcode.into(), elements.len() as u8);
+                self.emit_pattern_check(fail_jumps);
+
+                for (index, element) in elements.iter().enumerate() {
+                    path.push(index as u8);
+                    self.pattern_test(element, value_slot, path, fail_jumps);
+                    path.pop();
+                }
+            }
+            Pattern::Variant(enum_name, variant, fields) => {
+                self.emit_pattern_value(value_slot, path);
+                self.emit_enum_value(enum_name.clone());
+
+                let name_constant = self.property_name_constant(variant.clone());
+                self.emit_bytes(OpCode::OpMatchVariant.into(), name_constant);
+                self.emit_byte(fields.len() as u8);
+                self.emit_pattern_check(fail_jumps);
+
+                for (index, field) in fields.iter().enumerate() {
+                    path.push(index as u8);
+                    self.pattern_test(field, value_slot, path, fail_jumps);
+                    path.pop();
+                }
+            }
+            Pattern::Alternatives(alternatives) => {
+                let mut success_jumps = Vec::new();
+
+                for (index, alternative) in alternatives.iter().enumerate() {
+                    if index == alternatives.len() - 1 {
+                        self.pattern_test(alternative, value_slot, path, fail_jumps);
+                        break;
+                    }
+
+                    let mut alternative_fail_jumps = Vec::new();
+                    self.pattern_test(alternative, value_slot, path, &mut alternative_fail_jumps);
+                    success_jumps.push(self.emit_jump(OpCode::OpJump.into()));
+
+                    for fail_jump in alternative_fail_jumps {
+                        self.patch_jump(fail_jump);
+                    }
+                    self.emit_byte(OpCode::OpPop.into());
+                }
+
+                for success_jump in success_jumps {
+                    self.patch_jump(success_jump);
+                }
+            }
+        }
+    }
+
+    fn emit_pattern_check(&mut self, fail_jumps: &mut Vec<usize>) {
+        fail_jumps.push(self.emit_jump(OpCode::OpJumpIfFalse.into()));
+        self.emit_byte(OpCode::OpPop.into());
+    }
+
+    fn emit_pattern_value(&mut self, value_slot: u8, path: &[u8]) {
+        self.emit_bytes(OpCode::OpGetLocal.into(), value_slot);
+
+        for index in path {
+            self.emit_constant(Value::Int32(*index as i32), self.get_previous().get_line());
+            self.emit_byte(OpCode::OpGetIndex.into());
+        }
+    }
+
+    fn pattern(&mut self) -> Pattern {
+        let mut alternatives = vec![self.single_pattern()];
+        while self.is_match(&TokenPipe) {
+            alternatives.push(self.single_pattern());
+        }
+
+        if alternatives.len() == 1 {
+            return alternatives.pop().unwrap();
+        }
+
+        let mut bindings = Vec::new();
+        for alternative in &alternatives {
+            alternative.bindings(&mut Vec::new(), &mut bindings);
+        }
+        if !bindings.is_empty() {
+            self.parser.report_error(&"Cannot bind variables in alternative patterns".to_string());
+        }
+
+        Pattern::Alternatives(alternatives)
+    }
+
+    fn single_pattern(&mut self) -> Pattern {
+        self.parser.advance();
+        let token_type = self.get_previous().get_token_type().clone();
+
+        match token_type {
+            TokenIdentifier => {
+                let name = self.get_previous().get_lexeme(self.source);
+                if self.is_match(&TokenDot) {
+                    self.variant_pattern(name)
+                } else if name == "_" {
+                    Pattern::Wildcard
+                } else {
+                    Pattern::Binding(name)
+                }
+            }
+            TokenTrue => Pattern::Literal(Value::Bool(true)),
+            TokenFalse => Pattern::Literal(Value::Bool(false)),
+            TokenNull => Pattern::Literal(Value::Null),
+            TokenNumber | TokenMinus => self.number_pattern(token_type == TokenMinus),
+            TokenStringStart => self.string_pattern(),
+            TokenLeftParen => {
+                let elements = self.pattern_list(TokenRightParen);
+                match elements {
+                    (mut elements, false) if elements.len() == 1 => elements.pop().unwrap(),
+                    (elements, _) => Pattern::Tuple(elements),
+                }
+            }
+            TokenLeftBracket => Pattern::List(self.pattern_list(TokenRightBracket).0),
+            _ => {
+                self.parser.report_error(&"Expected pattern".to_string());
+                Pattern::Wildcard
+            }
+        }
+    }
+
+    fn variant_pattern(&mut self, enum_name: String) -> Pattern {
+        self.parser.consume(TokenIdentifier, "Expected variant name after '.' in pattern");
+        let variant = self.get_previous().get_lexeme(self.source);
+
+        let fields = if self.is_match(&TokenLeftParen) {
+            self.pattern_list(TokenRightParen).0
+        } else {
+            Vec::new()
+        };
+
+        if let Some(enumeration) = self.enums.get(&enum_name) {
+            let message = match enumeration.get_tag(&variant) {
+                None => Some(format!("Enum {} has no variant '{}'", enum_name, variant)),
+                Some(tag) if enumeration.get_variant(tag).arity() != fields.len() => {
+                    Some(
+                        format!(
+                            "Variant {}.{} holds {} values but the pattern has {}",
+                            enum_name,
+                            variant,
+                            enumeration.get_variant(tag).arity(),
+                            fields.len()
+                        )
+                    )
+                }
+                Some(_) => None,
+            };
+
+            if let Some(message) = message {
+                self.parser.report_error(&message);
+            }
+        }
+
+        Pattern::Variant(enum_name, variant, fields)
+    }
+
+    fn number_pattern(&mut self, is_negative: bool) -> Pattern {
+        if is_negative {
+            self.parser.consume(TokenNumber, "Expected number after '-' in pattern");
+        }
+
+        let lexeme = self.get_previous().get_lexeme(self.source);
+        let lexeme = if is_negative { format!("-{}", lexeme) } else { lexeme };
+
+        match Self::number_value(&lexeme) {
+            Some(value) => Pattern::Literal(value),
+            None => {
+                self.parser.report_error(&"Invalid number in pattern".to_string());
+                Pattern::Wildcard
+            }
+        }
+    }
+
+    fn string_pattern(&mut self) -> Pattern {
+        if self.is_match(&TokenStringEnd) {
+            return Pattern::Literal(Value::String("".to_string()));
+        }
+
+        self.parser.consume(TokenString, "Expected string in pattern");
+        let string = self.get_previous().get_lexeme_string(self.source);
+
+        if self.get_current().get_token_type() == &TokenInterpolationStart {
+            self.parser.report_error(&"Patterns cannot contain string interpolation".to_string());
+        }
+        self.parser.consume(TokenStringEnd, "Expected end of string in pattern");
+
+        Pattern::Literal(Value::String(string))
+    }
+
+    fn pattern_list(&mut self, closing: TokenType) -> (Vec<Pattern>, bool) {
+        let mut elements = Vec::new();
+        let mut trailing_comma = false;
+
+        while self.get_current().get_token_type() != &closing {
+            if elements.len() == (u8::MAX as usize) {
+                self.parser.report_error(&"Cannot have more than 255 elements in a pattern".to_string());
+            }
+            elements.push(self.pattern());
+
+            trailing_comma = self.is_match(&TokenComma);
+            if !trailing_comma {
+                break;
+            }
+        }
+
+        self.parser.consume(closing, "Expected closing bracket after patterns");
+        (elements, trailing_comma)
+    }
+}
@@ -0,0 +1,324 @@
+use crate::{ token::token_type::TokenType, value::{ StaticType, ValueType } };
+use super::Compiler;
+
+impl<'a> Compiler<'a> {
+    pub fn set_type_checking(&mut self, enabled: bool) {
+        self.type_checking = enabled;
+    }
+
+    pub fn declare_global_type(&mut self, name: String, static_type: StaticType) {
+        self.global_types.insert(name, static_type);
+    }
+
+    pub(super) fn set_expression_type(&mut self, static_type: StaticType) {
+        self.expression_type = static_type;
+    }
+
+    pub(super) fn take_expression_type(&mut self) -> StaticType {
+        std::mem::replace(&mut self.expression_type, StaticType::dynamic())
+    }
+
+    pub(super) fn set_assignment_type(&mut self, target: &ValueType) {
+        if !target.is_dynamic() {
+            self.set_expression_type(StaticType::new(target.clone()));
+        }
+    }
+
+    pub(super) fn set_logical_type(&mut self, left: StaticType) {
+        if self.expression_type != left {
+            self.set_expression_type(StaticType::dynamic());
+        }
+    }
+
+    pub(super) fn declare_static_type(&mut self, name: &String, static_type: StaticType) {
+        if self.current().scope_depth > 0 {
+            if let Some(slot) = self.resolve_local(name) {
+                self.current_mut().locals[slot as usize].static_type = static_type;
+            }
+        } else {
+            self.global_types.insert(name.clone(), static_type);
+        }
+    }
+
+    pub(super) fn global_type(&mut self, name: &String) -> StaticType {
+        if let Some(static_type) = self.global_types.get(name) {
+            return static_type.clone();
+        }
+
+        let line = self.get_previous().get_line();
+        if self.function_compilers.len() > 1 {
+            self.unresolved_globals.push((name.clone(), line));
+        } else {
+            self.report_type_error(format!("Undefined variable '{}'", name), line);
+        }
+
+        StaticType::dynamic()
+    }
+
+    pub(super) fn check_unresolved_globals(&mut self) {
+        for (name, line) in std::mem::take(&mut self.unresolved_globals) {
+            if !self.global_types.contains_key(&name) {
+                self.report_type_error(format!("Undefined variable '{}'", name), line);
+            }
+        }
+    }
+
+    pub(super) fn check_unary(&mut self, operator: &TokenType, operand: StaticType) -> StaticType {
+        match operator {
+            TokenType::TokenBang => StaticType::new(ValueType::Bool),
+            TokenType::TokenMinus if operand.is_dynamic() || operand.is_numeric() => operand,
+            TokenType::TokenMinus => {
+                self.report_type_error_here(
+                    format!("Cannot negate {:?}", operand.to_type_string())
+                );
+                StaticType::dynamic()
+            }
+            _ => StaticType::dynamic(),
+        }
+    }
+
+    pub(super) fn check_binary(
+        &mut self,
+        operator: &TokenType,
+        left: StaticType,
+        right: StaticType
+    ) -> StaticType {
+        let is_comparison = matches!(
+            operator,
+            TokenType::TokenEqualEqual |
+                TokenType::TokenBangEqual |
+                TokenType::TokenGreater |
+                TokenType::TokenGreaterEqual |
+                TokenType::TokenLess |
+                TokenType::TokenLessEqual
+        );
+
+        if left.is_dynamic() || right.is_dynamic() {
+            return if is_comparison {
+                StaticType::new(ValueType::Bool)
+            } else {
+                StaticType::dynamic()
+            };
+        }
+
+        let is_string = |t: &StaticType| t.get_value_type() == &ValueType::String && !t.is_nullable();
+        let both_numeric = left.is_numeric() && right.is_numeric();
+
+        let result = match operator {
+            TokenType::TokenEqualEqual | TokenType::TokenBangEqual => Some(ValueType::Bool),
+            _ if is_comparison && (both_numeric || (is_string(&left) && is_string(&right))) => {
+                Some(ValueType::Bool)
+            }
+            TokenType::TokenPlus if is_string(&left) || is_string(&right) => {
+                let other = if is_string(&left) { &right } else { &left };
+                (other.is_printable() && !other.may_be_null()).then_some(ValueType::String)
+            }
+            | TokenType::TokenPlus
+            | TokenType::TokenMinus
+            | TokenType::TokenStar
+            | TokenType::TokenWrappingPlus
+            | TokenType::TokenWrappingMinus
+            | TokenType::TokenWrappingStar
+            | TokenType::TokenSaturatingPlus
+            | TokenType::TokenSaturatingMinus
+            | TokenType::TokenSaturatingStar
+            | TokenType::TokenTildeSlash
+            | TokenType::TokenPercent if both_numeric => {
+                left.get_value_type().promote(right.get_value_type())
+            }
+            TokenType::TokenSlash if both_numeric => {
+                match left.get_value_type().promote(right.get_value_type()) {
+                    Some(ValueType::Float32) => Some(ValueType::Float32),
+                    _ => Some(ValueType::Float64),
+                }
+            }
+            TokenType::TokenPower if both_numeric => {
+                if left.get_value_type().is_float() || right.get_value_type().is_float() {
+                    left.get_value_type().promote(right.get_value_type())
+                } else {
+                    Some(ValueType::Dynamic)
+                }
+            }
+            _ => None,
+        };
+
+        if let Some(value_type) = result {
+            return StaticType::new(value_type);
+        }
+
+        let (verb, connective) = match operator {
+            TokenType::TokenPlus | TokenType::TokenWrappingPlus | TokenType::TokenSaturatingPlus => {
+                ("add", "and")
+            }
+            | TokenType::TokenMinus
+            | TokenType::TokenWrappingMinus
+            | TokenType::TokenSaturatingMinus => ("subtract", "and"),
+            TokenType::TokenStar | TokenType::TokenWrappingStar | TokenType::TokenSaturatingStar => {
+                ("multiply", "and")
+            }
+            TokenType::TokenSlash | TokenType::TokenTildeSlash => ("divide", "and"),
+            TokenType::TokenPercent => ("take the remainder of", "and"),
+            TokenType::TokenPower => ("raise", "to the power of"),
+            _ if is_comparison => ("compare", "and"),
+            _ => {
+                return StaticType::dynamic();
+            }
+        };
+
+        self.report_type_error_here(
+            format!(
+                "Cannot {} {:?} {} {:?}",
+                verb,
+                left.to_type_string(),
+                connective,
+                right.to_type_string()
+            )
+        );
+        StaticType::dynamic()
+    }
+
+    pub(super) fn check_cast(&mut self, operand: StaticType, target: &ValueType) -> StaticType {
+        let value_type = operand.get_value_type();
+        let is_castable =
+            operand.is_dynamic() ||
+            value_type.is_numeric() ||
+            value_type == &ValueType::String ||
+            (target == &ValueType::String && operand.is_printable());
+
+        if !is_castable {
+            self.report_type_error_here(
+                format!(
+                    "Cannot cast {:?} to {:?}",
+                    value_type.to_type_string(),
+                    target.to_type_string()
+                )
+            );
+        }
+
+        StaticType::new(target.clone())
+    }
+
+    pub(super) fn check_assignment(&mut self, target: &StaticType, value: &StaticType) {
+        if target.is_dynamic() || value.is_dynamic() {
+            return;
+        }
+
+        let expected_type = || {
+            format!(
+                "Expected type {:?} but got {:?}",
+                target.to_type_string(),
+                value.to_type_string()
+            )
+        };
+
+        if value.may_be_null() && !target.is_nullable() {
+            self.parser.report_error(&expected_type());
+            return;
+        }
+
+        let (value_type, target_type) = (value.get_value_type(), target.get_value_type());
+        let is_assignable =
+            value_type == &ValueType::Null ||
+            value_type == target_type ||
+            (value_type.is_integer() && target_type.is_numeric()) ||
+            (value_type.is_float() && target_type.is_float());
+
+        if !is_assignable {
+            self.report_type_error_here(expected_type());
+        }
+    }
+
+    pub(super) fn check_coalesce(&mut self, left: StaticType, right: StaticType) -> StaticType {
+        if left.get_value_type() == &ValueType::Null {
+            return right;
+        }
+
+        if left.is_dynamic() || left.get_value_type() != right.get_value_type() {
+            return StaticType::dynamic();
+        }
+
+        let left = left.non_nullable();
+        if right.is_nullable() {
+            left.nullable()
+        } else {
+            left
+        }
+    }
+
+    pub(super) fn check_enum_assignment(&mut self, enum_name: &String, value: &StaticType) {
+        if let Some(value_enum_name) = value.get_enum_name() {
+            if value_enum_name != enum_name {
+                self.report_type_error_here(
+                    format!("Expected type {:?} but got {:?}", enum_name, value_enum_name)
+                );
+            }
+        }
+    }
+
+    pub(super) fn check_index(&mut self, target: &StaticType, index: &StaticType) {
+        let is_collection = matches!(
+            target.get_value_type(),
+            ValueType::List | ValueType::Map | ValueType::Tuple | ValueType::Enum
+        );
+
+        if target.may_be_null() {
+            self.report_type_error_here(
+                format!("Cannot index {:?}, which may be null", target.to_type_string())
+            );
+        } else if !target.is_dynamic() && !is_collection {
+            self.report_type_error_here(
+                format!(
+                    "Can only index lists, maps, tuples and enum variants, but got {:?}",
+                    target.get_value_type().to_type_string()
+                )
+            );
+        } else if
+            matches!(target.get_value_type(), ValueType::List | ValueType::Tuple | ValueType::Enum) &&
+            !index.is_dynamic() &&
+            !index.get_value_type().is_integer()
+        {
+            self.report_type_error_here(
+                format!("Index must be an integer, but got {:?}", index.get_value_type().to_type_string())
+            );
+        } else if target.get_value_type() == &ValueType::Map && index.get_value_type().is_float() {
+            self.report_type_error_here(
+                format!(
+                    "Map keys must be strings, booleans or integers, but got {:?}",
+                    index.get_value_type().to_type_string()
+                )
+            );
+        }
+    }
+
+    pub(super) fn check_call(&mut self, callee: &StaticType, arg_count: usize) {
+        match callee.get_value_type() {
+            | ValueType::Function
+            | ValueType::Class
+            | ValueType::Dynamic
+            | ValueType::Empty => {}
+            _ => {
+                self.report_type_error_here("Can only call functions and classes".to_string());
+                return;
+            }
+        }
+
+        if let Some(arity) = callee.get_arity() {
+            if arity != arg_count {
+                self.report_type_error_here(
+                    format!("Expected {} arguments but got {}", arity, arg_count)
+                );
+            }
+        }
+    }
+
+    fn report_type_error_here(&mut self, message: String) {
+        let line = self.get_previous().get_line();
+        self.report_type_error(message, line);
+    }
+
+    fn report_type_error(&mut self, message: String, line: usize) {
+        if self.type_checking {
+            self.parser.report_error_at_line(&message, line);
+        }
+    }
+}
@@ -118,6 +118,17 @@ impl<'a> Lexer<'a> {
         self.peek() == "}" && !(self.peek_next() == "}") && !(self.peek_previous() == "}")
     }
 
+    pub(super) fn is_postfix_step(&self) -> bool {
+        let before = self.source[..self.start].chars().next_back();
+        let after = self.source[self.current + 1..].chars().next();
+
+        let ends_operand = before.is_some_and(|c| c.is_alphanumeric() || matches!(c, '_' | ')' | ']'));
+        let starts_operand = after.is_some_and(
+            |c| c.is_alphanumeric() || matches!(c, '_' | '(' | '[' | '"')
+        );
+        ends_operand && !starts_operand
+    }
+
     pub(super) fn string(&mut self) -> Token {
         loop {
             if self.peek() == "\"" && !(self.peek_previous() == "\\") {
@@ -197,17 +208,65 @@ impl<'a> Lexer<'a> {
         self.make_token(self.identifier_type())
     }
 
+    pub(super) fn label(&mut self) -> Token {
+        if !is_alphabetic(self.peek()) {
+            return self.error_token("Expected label name after '\''");
+        }
+
+        loop {
+            if !is_alphabetic(self.peek()) && !is_digit(self.peek()) {
+                break;
+            }
+
+            self.current += 1;
+        }
+
+        self.make_token(TokenLabel)
+    }
+
     fn identifier_type(&self) -> TokenType {
         match self.get_character(self.start).0 {
-            "a" => self.check_keyword(1, 2, "nd", TokenAnd),
-            "c" => self.check_keyword(1, 4, "lass", TokenClass),
-            "e" => self.check_keyword(1, 3, "lse", TokenElse),
+            "a" => {
+                if self.current - self.start > 1 {
+                    match self.get_character(self.start + 1).0 {
+                        "n" => self.check_keyword(2, 1, "d", TokenAnd),
+                        "s" => self.check_keyword(2, 0, "", TokenAs),
+                        _ => TokenIdentifier,
+                    }
+                } else {
+                    TokenIdentifier
+                }
+            }
+            "b" => self.check_keyword(1, 4, "reak", TokenBreak),
+            "c" => {
+                if self.current - self.start > 1 {
+                    match self.get_character(self.start + 1).0 {
+                        "l" => self.check_keyword(2, 3, "ass", TokenClass),
+                        "o" => self.check_keyword(2, 6, "ntinue", TokenContinue),
+                        _ => TokenIdentifier,
+                    }
+                } else {
+                    TokenIdentifier
+                }
+            }
+            "e" => {
+                if self.current - self.start > 1 {
+                    match self.get_character(self.start + 1).0 {
+                        "l" => self.check_keyword(2, 2, "se", TokenElse),
+                        "n" => self.check_keyword(2, 2, "um", TokenEnum),
+                        _ => TokenIdentifier,
+                    }
+                } else {
+                    TokenIdentifier
+                }
+            }
             "f" => {
                 if self.current - self.start > 1 {
                     match self.get_character(self.start + 1).0 {
                         "a" => self.check_keyword(2, 3, "lse", TokenFalse),
                         "o" => self.check_keyword(2, 1, "r", TokenFor),
-                        "n" => TokenFn,
+                        "r" => self.check_keyword(2, 2, "om", TokenFrom),
+                        "n" => self.check_keyword(2, 0, "", TokenFn),
                         "3" => self.check_keyword(2, 1, "2", TokenTypeFloat32),
                         "6" => self.check_keyword(2, 1, "4", TokenTypeFloat64),
                         _ => TokenIdentifier,
@@ -219,12 +278,19 @@ impl<'a> Lexer<'a> {
             "i" => {
                 if self.current - self.start > 1 {
                     match self.get_character(self.start + 1).0 {
-                        "f" => TokenIf,
-                        "8" => TokenTypeInt8,
+                        "f" => self.check_keyword(2, 0, "", TokenIf),
+                        "m" => self.check_keyword(2, 4, "port", TokenImport),
+                        "8" => self.check_keyword(2, 0, "", TokenTypeInt8),
                         "1" => self.check_keyword(2, 1, "6", TokenTypeInt16),
                         "3" => self.check_keyword(2, 1, "2", TokenTypeInt32),
                         "6" => self.check_keyword(2, 1, "4", TokenTypeInt64),
-                        "n" => self.check_keyword(2, 1, "n", TokenTypeInt),
+                        "n" => {
+                            if self.current - self.start == 2 {
+                                TokenIn
+                            } else {
+                                self.check_keyword(2, 1, "t", TokenTypeInt)
+                            }
+                        }
                         _ => TokenIdentifier,
                     }
                 } else {
@@ -233,11 +299,41 @@ impl<'a> Lexer<'a> {
             }
             // "l" => self.check_keyword(1, 2, "et", TokenLet),
             "n" => self.check_keyword(1, 3, "ull", TokenNull),
-            "m" => self.check_keyword(1, 2, "ut", TokenMutable),
+            "m" => {
+                if self.current - self.start > 1 {
+                    match self.get_character(self.start + 1).0 {
+                        "a" => self.check_keyword(2, 3, "tch", TokenMatch),
+                        "u" => self.check_keyword(2, 1, "t", TokenMutable),
+                        _ => TokenIdentifier,
+                    }
+                } else {
+                    TokenIdentifier
+                }
+            }
             "o" => self.check_keyword(1, 1, "r", TokenOr),
-            "p" => self.check_keyword(1, 4, "rint", TokenPrint),
+            "p" => {
+                if self.current - self.start > 1 {
+                    match self.get_character(self.start + 1).0 {
+                        "r" => self.check_keyword(2, 3, "int", TokenPrint),
+                        "u" => self.check_keyword(2, 1, "b", TokenPub),
+                        _ => TokenIdentifier,
+                    }
+                } else {
+                    TokenIdentifier
+                }
+            }
             "r" => self.check_keyword(1, 5, "eturn", TokenReturn),
-            "s" => self.check_keyword(1, 5, "tring", TokenTypeString),
+            "s" => {
+                if self.current - self.start > 1 {
+                    match self.get_character(self.start + 1).0 {
+                        "t" => self.check_keyword(2, 4, "ring", TokenTypeString),
+                        "u" => self.check_keyword(2, 3, "per", TokenSuper),
+                        _ => TokenIdentifier,
+                    }
+                } else {
+                    TokenIdentifier
+                }
+            }
             "t" => {
                 if self.current - self.start > 1 {
                     match self.get_character(self.start + 1).0 {
@@ -249,6 +345,20 @@ impl<'a> Lexer<'a> {
                     TokenIdentifier
                 }
             }
+            "u" => {
+                if self.current - self.start > 1 {
+                    match self.get_character(self.start + 1).0 {
+                        "8" => self.check_keyword(2, 0, "", TokenTypeUnsignedInt8),
+                        "1" => self.check_keyword(2, 1, "6", TokenTypeUnsignedInt16),
+                        "3" => self.check_keyword(2, 1, "2", TokenTypeUnsignedInt32),
+                        "6" => self.check_keyword(2, 1, "4", TokenTypeUnsignedInt64),
+                        "i" => self.check_keyword(2, 2, "nt", TokenTypeUnsignedInt),
+                        _ => TokenIdentifier,
+                    }
+                } else {
+                    TokenIdentifier
+                }
+            }
             "w" => self.check_keyword(1, 4, "hile", TokenWhile),
             _ => TokenIdentifier,
         }
@@ -13,6 +13,7 @@ pub struct Lexer<'a> {
     line: usize,
     string_count: u8,
     interpolation_count: u8,
+    interpolation_braces: Vec<u8>,
 }
 
 impl<'a> Lexer<'a> {
@@ -24,6 +25,7 @@ impl<'a> Lexer<'a> {
             source: source,
             string_count: 0,
             interpolation_count: 0,
+            interpolation_braces: Vec::new(),
         }
     }
 
@@ -59,11 +61,19 @@ impl<'a> Lexer<'a> {
         match c {
             "(" => self.make_token(TokenLeftParen),
             ")" => self.make_token(TokenRightParen),
+            "[" => self.make_token(TokenLeftBracket),
+            "]" => self.make_token(TokenRightBracket),
             "{" => {
-                if self.string_count > 0 && !(self.peek() == "{") {
+                if self.string_count > self.interpolation_count && !(self.peek() == "{") {
                     self.interpolation_count += 1;
+                    self.interpolation_braces.push(0);
                     self.make_token(TokenInterpolationStart)
                 } else {
+                    if self.string_count == self.interpolation_count {
+                        if let Some(braces) = self.interpolation_braces.last_mut() {
+                            *braces += 1;
+                        }
+                    }
                     self.make_token(TokenLeftBrace)
                 }
             }
@@ -73,7 +83,15 @@ impl<'a> Lexer<'a> {
                         self.current += 1;
                         return self.make_token(TokenRightBrace);
                     } else if self.string_count == self.interpolation_count {
+                        if let Some(braces) = self.interpolation_braces.last_mut() {
+                            if *braces > 0 {
+                                *braces -= 1;
+                                return self.make_token(TokenRightBrace);
+                            }
+                        }
+
                         self.interpolation_count -= 1;
+                        self.interpolation_braces.pop();
                         return self.make_token(TokenInterpolationEnd);
                     }
                 } else if self.string_count > self.interpolation_count {
@@ -91,20 +109,89 @@ impl<'a> Lexer<'a> {
                 }
             }
             ";" => self.make_token(TokenSemicolon),
+            "|" => self.make_token(TokenPipe),
+            "?" => {
+                if self.match_char("?") {
+                    self.make_token(TokenQuestionQuestion)
+                } else if self.match_char(".") {
+                    self.make_token(TokenQuestionDot)
+                } else {
+                    self.make_token(TokenQuestion)
+                }
+            }
             "," => self.make_token(TokenComma),
-            "." => self.make_token(TokenDot),
-            "-" => self.make_token(TokenMinus),
-            "+" => self.make_token(TokenPlus),
+            "." => {
+                if self.match_char(".") {
+                    self.make_token(TokenDotDot)
+                } else {
+                    self.make_token(TokenDot)
+                }
+            }
+            "'" => self.label(),
+            "-" => {
+                if self.match_char("%") {
+                    self.make_token(TokenWrappingMinus)
+                } else if self.match_char("|") {
+                    self.make_token(TokenSaturatingMinus)
+                } else if self.match_char("=") {
+                    self.make_token(TokenMinusEqual)
+                } else if self.peek() == "-" && self.is_postfix_step() {
+                    self.current += 1;
+                    self.make_token(TokenMinusMinus)
+                } else {
+                    self.make_token(TokenMinus)
+                }
+            }
+            "+" => {
+                if self.match_char("%") {
+                    self.make_token(TokenWrappingPlus)
+                } else if self.match_char("|") {
+                    self.make_token(TokenSaturatingPlus)
+                } else if self.match_char("=") {
+                    self.make_token(TokenPlusEqual)
+                } else if self.peek() == "+" && self.is_postfix_step() {
+                    self.current += 1;
+                    self.make_token(TokenPlusPlus)
+                } else {
+                    self.make_token(TokenPlus)
+                }
+            }
             "/" => {
                 if self.match_char("*") {
                     self.scan_comment_block();
                     self.scan_token()
+                } else if self.match_char("=") {
+                    self.make_token(TokenSlashEqual)
                 } else {
                     self.make_token(TokenSlash)
                 }
             }
-            "*" => self.make_token(TokenStar),
-            "^" => self.make_token(TokenPower),
+            "*" => {
+                if self.match_char("%") {
+                    self.make_token(TokenWrappingStar)
+                } else if self.match_char("|") {
+                    self.make_token(TokenSaturatingStar)
+                } else if self.match_char("=") {
+                    self.make_token(TokenStarEqual)
+                } else {
+                    self.make_token(TokenStar)
+                }
+            }
+            "^" => {
+                if self.match_char("=") {
+                    self.make_token(TokenPowerEqual)
+                } else {
+                    self.make_token(TokenPower)
+                }
+            }
+            "%" => self.make_token(TokenPercent),
+            "~" => {
+                if self.match_char("/") {
+                    self.make_token(TokenTildeSlash)
+                } else {
+                    self.error_token("Expected '/' after '~'")
+                }
+            }
             "!" => {
                 if self.match_char("=") {
                     self.make_token(TokenBangEqual)
@@ -115,6 +202,8 @@ impl<'a> Lexer<'a> {
             "=" => {
                 if self.match_char("=") {
                     self.make_token(TokenEqualEqual)
+                } else if self.match_char(">") {
+                    self.make_token(TokenArrow)
                 } else {
                     self.make_token(TokenEqual)
                 }
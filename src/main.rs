@@ -11,22 +11,33 @@ mod parser;
 mod precedence;
 mod parse_rule;
 mod macros;
+mod object;
 
-use std::{ io::{ self, stdout, BufRead, Write }, net::TcpListener, process };
+use std::{
+    io::{ self, stdout, BufRead, Write },
+    net::TcpListener,
+    path::Path,
+    process,
+    time::{ SystemTime, UNIX_EPOCH },
+};
 
 use util::print::print_error;
+use value::Value;
 use vm::VM;
 
-use crate::vm::InterpretResult;
+use crate::vm::{ InterpretResult, RuntimeError };
 
 fn main() {
-    let args: Vec<String> = std::env::args().collect();
+    let mut args: Vec<String> = std::env::args().collect();
+
+    let type_checking = args.iter().any(|arg| arg == "--type-check");
+    args.retain(|arg| arg != "--type-check");
 
     match args.len() {
-        1 => run_prompt(),
-        2 => run_file(&args[1]),
+        1 => run_prompt(type_checking),
+        2 => run_file(&args[1], type_checking),
         _ => {
-            print_error("Usage: viskum [path]");
+            print_error("Usage: viskum [--type-check] [path]");
             std::process::exit(64);
         }
     }
@@ -36,8 +47,19 @@ fn main() {
     vm.free_vm()
 }
 
-fn run(source: &str, vm: &mut VM) {
-    match vm.interpret(source) {
+fn register_natives(vm: &mut VM) {
+    vm.register_native("clock", 0, clock).unwrap();
+}
+
+fn clock(_vm: &mut VM, _args: &[Value]) -> Result<Value, RuntimeError> {
+    let elapsed = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_err(|error| RuntimeError::new(&error.to_string()))?;
+    Ok(Value::Float64(elapsed.as_secs_f64()))
+}
+
+fn run(result: InterpretResult) {
+    match result {
         InterpretResult::CompileError => process::exit(65),
         InterpretResult::RuntimeError => process::exit(70),
         InterpretResult::Ok => {}
@@ -45,10 +67,12 @@ fn run(source: &str, vm: &mut VM) {
     }
 }
 
-fn run_file(path: &String) {
+fn run_file(path: &String, type_checking: bool) {
     let vm = &mut VM::new();
+    vm.set_type_checking(type_checking);
+    register_natives(vm);
     match std::fs::read_to_string(path) {
-        Ok(str) => run(str.as_str(), vm),
+        Ok(str) => run(vm.interpret_file(str.as_str(), Path::new(path))),
         Err(e) => {
             print_error(format!("There was an error while reading file: {}", e).as_str());
             process::exit(64);
@@ -56,8 +80,10 @@ fn run_file(path: &String) {
     }
 }
 
-fn run_prompt() {
+fn run_prompt(type_checking: bool) {
     let vm = &mut VM::new();
+    vm.set_type_checking(type_checking);
+    register_natives(vm);
     let stdin = io::stdin();
     print!("> ");
     let _ = stdout().flush();
@@ -66,7 +92,7 @@ fn run_prompt() {
             if line.is_empty() {
                 break;
             }
-            run(line.as_str(), vm);
+            run(vm.interpret(line.as_str()));
         } else {
             break;
         }
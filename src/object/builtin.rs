@@ -0,0 +1,100 @@
+use std::{ fmt, rc::Rc };
+
+use crate::{ value::{ Value, ValueType }, vm::{ RuntimeError, VM } };
+
+use super::{ NativeFn, NativeFunction, LIST_METHODS, MAP_METHODS, TUPLE_METHODS };
+
+#[derive(Clone, Copy)]
+pub struct BuiltinMethod {
+    name: &'static str,
+    arity: usize,
+    mutating: bool,
+    function: NativeFn,
+}
+
+impl BuiltinMethod {
+    pub const fn new(
+        name: &'static str,
+        arity: usize,
+        mutating: bool,
+        function: NativeFn
+    ) -> Self {
+        Self { name, arity, mutating, function }
+    }
+
+    pub fn is_mutating(&self) -> bool {
+        self.mutating
+    }
+
+    pub fn to_native(self) -> NativeFunction {
+        NativeFunction::new(self.name.to_string(), self.arity + 1, self.function)
+    }
+}
+
+pub fn get_builtin_methods(value_type: &ValueType) -> &'static [BuiltinMethod] {
+    match value_type {
+        ValueType::List => &LIST_METHODS,
+        ValueType::Map => &MAP_METHODS,
+        ValueType::Tuple => &TUPLE_METHODS,
+        _ => &[],
+    }
+}
+
+pub fn get_builtin_method(value_type: &ValueType, name: &str) -> Option<BuiltinMethod> {
+    get_builtin_methods(value_type)
+        .iter()
+        .find(|method| method.name == name)
+        .copied()
+}
+
+pub fn is_mutating_method(value_type: &ValueType, name: &str) -> bool {
+    let value_types = match value_type {
+        ValueType::Dynamic => &[ValueType::List, ValueType::Map, ValueType::Tuple][..],
+        _ => std::slice::from_ref(value_type),
+    };
+    value_types
+        .iter()
+        .any(|value_type| get_builtin_method(value_type, name).is_some_and(|method| method.is_mutating()))
+}
+
+pub fn native_len(vm: &mut VM, args: &[Value]) -> Result<Value, RuntimeError> {
+    match vm.get_builtin(&args[0], "len") {
+        Some(native) => native.call(vm, args),
+        None => Err(format!("Cannot take the length of {}", args[0].to_type_string()).into()),
+    }
+}
+
+pub struct BoundBuiltin {
+    receiver: Value,
+    native: Rc<NativeFunction>,
+}
+
+impl BoundBuiltin {
+    pub fn new(receiver: Value, native: Rc<NativeFunction>) -> Self {
+        Self { receiver, native }
+    }
+
+    pub fn get_receiver(&self) -> &Value {
+        &self.receiver
+    }
+
+    pub fn get_native(&self) -> &Rc<NativeFunction> {
+        &self.native
+    }
+
+    pub fn get_arity(&self) -> usize {
+        self.native.get_arity() - 1
+    }
+}
+
+impl fmt::Debug for BoundBuiltin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<builtin method {}>", self.native.get_name())
+    }
+}
+
+impl PartialEq for BoundBuiltin {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self, other)
+    }
+}
@@ -0,0 +1,112 @@
+use std::{ cell::RefCell, collections::HashMap, fmt, rc::Rc };
+
+use crate::value::Value;
+
+use super::Closure;
+
+pub struct Class {
+    name: String,
+    methods: HashMap<String, Rc<Closure>>,
+}
+
+impl Class {
+    pub fn new(name: String) -> Self {
+        Self { name, methods: HashMap::new() }
+    }
+
+    pub fn get_name(&self) -> &String {
+        &self.name
+    }
+
+    pub fn get_method(&self, name: &str) -> Option<Rc<Closure>> {
+        self.methods.get(name).cloned()
+    }
+
+    pub fn add_method(&mut self, name: String, method: Rc<Closure>) {
+        self.methods.insert(name, method);
+    }
+
+    pub fn inherit(&mut self, superclass: &Class) {
+        for (name, method) in &superclass.methods {
+            self.methods.insert(name.clone(), method.clone());
+        }
+    }
+}
+
+pub struct Instance {
+    class: Rc<RefCell<Class>>,
+    fields: HashMap<String, Value>,
+}
+
+impl Instance {
+    pub fn new(class: Rc<RefCell<Class>>) -> Self {
+        Self { class, fields: HashMap::new() }
+    }
+
+    pub fn get_class(&self) -> &Rc<RefCell<Class>> {
+        &self.class
+    }
+
+    pub fn get_field(&self, name: &str) -> Option<Value> {
+        self.fields.get(name).cloned()
+    }
+
+    pub fn set_field(&mut self, name: String, value: Value) {
+        self.fields.insert(name, value);
+    }
+}
+
+pub struct BoundMethod {
+    receiver: Value,
+    method: Rc<Closure>,
+}
+
+impl BoundMethod {
+    pub fn new(receiver: Value, method: Rc<Closure>) -> Self {
+        Self { receiver, method }
+    }
+
+    pub fn get_receiver(&self) -> &Value {
+        &self.receiver
+    }
+
+    pub fn get_method(&self) -> &Rc<Closure> {
+        &self.method
+    }
+}
+
+impl fmt::Debug for Class {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<class {}>", self.name)
+    }
+}
+
+impl fmt::Debug for Instance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<{} instance>", self.class.borrow().get_name())
+    }
+}
+
+impl fmt::Debug for BoundMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<bound method {}>", self.method.get_function().get_name())
+    }
+}
+
+impl PartialEq for Class {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self, other)
+    }
+}
+
+impl PartialEq for Instance {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self, other)
+    }
+}
+
+impl PartialEq for BoundMethod {
+    fn eq(&self, other: &Self) -> bool {
+        self.receiver == other.receiver && Rc::ptr_eq(&self.method, &other.method)
+    }
+}
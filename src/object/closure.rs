@@ -0,0 +1,47 @@
+use std::{ cell::RefCell, fmt, rc::Rc };
+
+use crate::value::Value;
+
+use super::{ Function, Globals };
+
+#[derive(Debug)]
+pub enum Upvalue {
+    Open(usize),
+    Closed(Value),
+}
+
+pub struct Closure {
+    function: Rc<Function>,
+    upvalues: Vec<Rc<RefCell<Upvalue>>>,
+    globals: Globals,
+}
+
+impl Closure {
+    pub fn new(function: Rc<Function>, upvalues: Vec<Rc<RefCell<Upvalue>>>, globals: Globals) -> Self {
+        Self { function, upvalues, globals }
+    }
+
+    pub fn get_globals(&self) -> &Globals {
+        &self.globals
+    }
+
+    pub fn get_function(&self) -> &Rc<Function> {
+        &self.function
+    }
+
+    pub fn get_upvalue(&self, index: usize) -> Rc<RefCell<Upvalue>> {
+        self.upvalues[index].clone()
+    }
+}
+
+impl fmt::Debug for Closure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<closure {}>", self.function.get_name())
+    }
+}
+
+impl PartialEq for Closure {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self, other)
+    }
+}
@@ -0,0 +1,175 @@
+use std::{ fmt, rc::Rc };
+
+use crate::value::{ Value, ValueType };
+
+pub struct Enum {
+    name: String,
+    variants: Vec<Variant>,
+}
+
+impl Enum {
+    pub fn new(name: String) -> Self {
+        Self { name, variants: Vec::new() }
+    }
+
+    pub fn get_name(&self) -> &String {
+        &self.name
+    }
+
+    pub fn get_variants(&self) -> &Vec<Variant> {
+        &self.variants
+    }
+
+    pub fn add_variant(&mut self, name: String, field_types: Vec<ValueType>) -> Result<(), String> {
+        if self.get_tag(&name).is_some() {
+            return Err(format!("Enum {} already has a variant named {}", self.name, name));
+        }
+
+        self.variants.push(Variant { name, field_types });
+        Ok(())
+    }
+
+    pub fn get_tag(&self, name: &str) -> Option<usize> {
+        self.variants.iter().position(|variant| variant.name == name)
+    }
+
+    pub fn get_variant(&self, tag: usize) -> &Variant {
+        &self.variants[tag]
+    }
+}
+
+pub struct Variant {
+    name: String,
+    field_types: Vec<ValueType>,
+}
+
+impl Variant {
+    pub fn get_name(&self) -> &String {
+        &self.name
+    }
+
+    pub fn arity(&self) -> usize {
+        self.field_types.len()
+    }
+}
+
+pub struct EnumVariant {
+    enumeration: Rc<Enum>,
+    tag: usize,
+    payload: Vec<Value>,
+}
+
+impl EnumVariant {
+    pub fn new(enumeration: Rc<Enum>, tag: usize, args: Vec<Value>) -> Result<Self, String> {
+        let variant = enumeration.get_variant(tag);
+        if args.len() != variant.arity() {
+            return Err(
+                format!(
+                    "Expected {} arguments but got {} when calling {}.{}",
+                    variant.arity(),
+                    args.len(),
+                    enumeration.name,
+                    variant.name
+                )
+            );
+        }
+
+        let payload = args
+            .iter()
+            .zip(&variant.field_types)
+            .map(|(arg, field_type)| arg.coerce_to(field_type))
+            .collect::<Result<Vec<Value>, String>>()?;
+
+        Ok(Self { enumeration, tag, payload })
+    }
+
+    pub fn get_enum(&self) -> &Rc<Enum> {
+        &self.enumeration
+    }
+
+    pub fn get_tag(&self) -> usize {
+        self.tag
+    }
+
+    pub fn get_variant(&self) -> &Variant {
+        self.enumeration.get_variant(self.tag)
+    }
+
+    pub fn get_payload(&self) -> &Vec<Value> {
+        &self.payload
+    }
+
+    pub fn get(&self, index: usize) -> Result<Value, String> {
+        self.payload
+            .get(index)
+            .cloned()
+            .ok_or_else(|| {
+                format!(
+                    "Index {} is out of bounds for {}.{}, which holds {} values",
+                    index,
+                    self.enumeration.name,
+                    self.get_variant().name,
+                    self.payload.len()
+                )
+            })
+    }
+
+    pub fn is_same_variant(&self, other: &EnumVariant) -> bool {
+        Rc::ptr_eq(&self.enumeration, &other.enumeration) && self.tag == other.tag
+    }
+}
+
+pub struct VariantConstructor {
+    enumeration: Rc<Enum>,
+    tag: usize,
+}
+
+impl VariantConstructor {
+    pub fn new(enumeration: Rc<Enum>, tag: usize) -> Self {
+        Self { enumeration, tag }
+    }
+
+    pub fn get_name(&self) -> String {
+        format!("{}.{}", self.enumeration.name, self.enumeration.get_variant(self.tag).name)
+    }
+
+    pub fn construct(&self, args: Vec<Value>) -> Result<EnumVariant, String> {
+        EnumVariant::new(self.enumeration.clone(), self.tag, args)
+    }
+}
+
+impl fmt::Debug for Enum {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<enum {}>", self.name)
+    }
+}
+
+impl fmt::Debug for EnumVariant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}{:?}", self.enumeration.name, self.get_variant().name, self.payload)
+    }
+}
+
+impl fmt::Debug for VariantConstructor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<constructor {}>", self.get_name())
+    }
+}
+
+impl PartialEq for Enum {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self, other)
+    }
+}
+
+impl PartialEq for EnumVariant {
+    fn eq(&self, other: &Self) -> bool {
+        self.is_same_variant(other) && self.payload == other.payload
+    }
+}
+
+impl PartialEq for VariantConstructor {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.enumeration, &other.enumeration) && self.tag == other.tag
+    }
+}
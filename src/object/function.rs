@@ -0,0 +1,83 @@
+use std::fmt;
+
+use crate::chunk::Chunk;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum FunctionType {
+    Script,
+    Function,
+    Method,
+    Initializer,
+}
+
+pub struct Function {
+    name: String,
+    arity: usize,
+    upvalue_count: usize,
+    chunk: Chunk,
+    exports: Option<Vec<String>>,
+    file: Option<String>,
+}
+
+impl Function {
+    pub fn new(name: String) -> Self {
+        Self { name, arity: 0, upvalue_count: 0, chunk: Chunk::new(), exports: None, file: None }
+    }
+
+    pub fn get_name(&self) -> &String {
+        &self.name
+    }
+
+    pub fn get_arity(&self) -> usize {
+        self.arity
+    }
+
+    pub fn increment_arity(&mut self) -> usize {
+        self.arity += 1;
+        self.arity
+    }
+
+    pub fn get_upvalue_count(&self) -> usize {
+        self.upvalue_count
+    }
+
+    pub fn set_upvalue_count(&mut self, upvalue_count: usize) {
+        self.upvalue_count = upvalue_count;
+    }
+
+    pub fn get_chunk(&self) -> &Chunk {
+        &self.chunk
+    }
+
+    pub fn get_chunk_mut(&mut self) -> &mut Chunk {
+        &mut self.chunk
+    }
+
+    pub fn get_exports(&self) -> Option<&Vec<String>> {
+        self.exports.as_ref()
+    }
+
+    pub fn set_exports(&mut self, exports: Vec<String>) {
+        self.exports = Some(exports);
+    }
+
+    pub fn get_file(&self) -> Option<&String> {
+        self.file.as_ref()
+    }
+
+    pub fn set_file(&mut self, file: String) {
+        self.file = Some(file);
+    }
+}
+
+impl fmt::Debug for Function {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<fn {}>", self.name)
+    }
+}
+
+impl PartialEq for Function {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self, other)
+    }
+}
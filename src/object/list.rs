@@ -0,0 +1,75 @@
+use std::{ cell::RefCell, rc::Rc };
+
+use crate::{ value::Value, vm::{ RuntimeError, VM } };
+
+use super::BuiltinMethod;
+
+#[derive(Debug, PartialEq)]
+pub struct List {
+    items: Vec<Value>,
+}
+
+impl List {
+    pub fn new(items: Vec<Value>) -> Self {
+        Self { items }
+    }
+
+    pub fn get_items(&self) -> &Vec<Value> {
+        &self.items
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn get(&self, index: usize) -> Result<Value, String> {
+        self.items.get(index).cloned().ok_or_else(|| self.out_of_bounds(index))
+    }
+
+    pub fn set(&mut self, index: usize, value: Value) -> Result<(), String> {
+        if index >= self.items.len() {
+            return Err(self.out_of_bounds(index));
+        }
+
+        self.items[index] = value;
+        Ok(())
+    }
+
+    pub fn push(&mut self, value: Value) {
+        self.items.push(value);
+    }
+
+    pub fn pop(&mut self) -> Result<Value, String> {
+        self.items.pop().ok_or_else(|| "Cannot pop from an empty list".to_string())
+    }
+
+    fn out_of_bounds(&self, index: usize) -> String {
+        format!("Index {} is out of bounds for a list of length {}", index, self.items.len())
+    }
+}
+
+pub const LIST_METHODS: [BuiltinMethod; 3] = [
+    BuiltinMethod::new("len", 0, false, list_len),
+    BuiltinMethod::new("push", 1, true, list_push),
+    BuiltinMethod::new("pop", 0, true, list_pop),
+];
+
+fn as_list(receiver: &Value) -> &Rc<RefCell<List>> {
+    match receiver {
+        Value::List(list) => list,
+        _ => unreachable!("List methods are only bound to lists"),
+    }
+}
+
+fn list_len(_vm: &mut VM, args: &[Value]) -> Result<Value, RuntimeError> {
+    Ok(Value::Int64(as_list(&args[0]).borrow().len() as i64))
+}
+
+fn list_push(_vm: &mut VM, args: &[Value]) -> Result<Value, RuntimeError> {
+    as_list(&args[0]).borrow_mut().push(args[1].clone());
+    Ok(Value::Null)
+}
+
+fn list_pop(_vm: &mut VM, args: &[Value]) -> Result<Value, RuntimeError> {
+    Ok(as_list(&args[0]).borrow_mut().pop()?)
+}
@@ -0,0 +1,148 @@
+use std::{ cell::RefCell, collections::HashMap, rc::Rc };
+
+use crate::{ value::Value, vm::{ RuntimeError, VM } };
+
+use super::BuiltinMethod;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MapKey {
+    Integer(i128),
+    String(String),
+    Bool(bool),
+}
+
+impl TryFrom<&Value> for MapKey {
+    type Error = String;
+
+    fn try_from(value: &Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::String(a) => Ok(MapKey::String(a.clone())),
+            Value::Bool(a) => Ok(MapKey::Bool(*a)),
+            _ =>
+                value
+                    .as_integer()
+                    .map(MapKey::Integer)
+                    .ok_or_else(||
+                        format!(
+                            "Map keys must be strings, booleans or integers, but got {:?}",
+                            value.to_type_string()
+                        )
+                    ),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Default)]
+pub struct Map {
+    entries: Vec<(Value, Value)>,
+    indices: HashMap<MapKey, usize>,
+}
+
+impl Map {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get_entries(&self) -> &Vec<(Value, Value)> {
+        &self.entries
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn get(&self, key: &Value) -> Result<Value, String> {
+        match self.indices.get(&MapKey::try_from(key)?) {
+            Some(index) => Ok(self.entries[*index].1.clone()),
+            None => Err(Map::missing_key(key)),
+        }
+    }
+
+    pub fn contains(&self, key: &Value) -> Result<bool, String> {
+        Ok(self.indices.contains_key(&MapKey::try_from(key)?))
+    }
+
+    pub fn insert(&mut self, key: Value, value: Value) -> Result<(), String> {
+        let map_key = MapKey::try_from(&key)?;
+
+        match self.indices.get(&map_key) {
+            Some(index) => {
+                self.entries[*index].1 = value;
+            }
+            None => {
+                self.indices.insert(map_key, self.entries.len());
+                self.entries.push((key, value));
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn remove(&mut self, key: &Value) -> Result<Value, String> {
+        let index = match self.indices.remove(&MapKey::try_from(key)?) {
+            Some(index) => index,
+            None => {
+                return Err(Map::missing_key(key));
+            }
+        };
+
+        for later_index in self.indices.values_mut() {
+            if *later_index > index {
+                *later_index -= 1;
+            }
+        }
+
+        Ok(self.entries.remove(index).1)
+    }
+
+    fn missing_key(key: &Value) -> String {
+        format!("Key {} does not exist in map", key.to_repr_string())
+    }
+}
+
+pub const MAP_METHODS: [BuiltinMethod; 5] = [
+    BuiltinMethod::new("len", 0, false, map_len),
+    BuiltinMethod::new("keys", 0, false, map_keys),
+    BuiltinMethod::new("values", 0, false, map_values),
+    BuiltinMethod::new("has", 1, false, map_has),
+    BuiltinMethod::new("remove", 1, true, map_remove),
+];
+
+fn as_map(receiver: &Value) -> &Rc<RefCell<Map>> {
+    match receiver {
+        Value::Map(map) => map,
+        _ => unreachable!("Map methods are only bound to maps"),
+    }
+}
+
+fn map_len(_vm: &mut VM, args: &[Value]) -> Result<Value, RuntimeError> {
+    Ok(Value::Int64(as_map(&args[0]).borrow().len() as i64))
+}
+
+fn map_keys(_vm: &mut VM, args: &[Value]) -> Result<Value, RuntimeError> {
+    let keys = as_map(&args[0])
+        .borrow()
+        .get_entries()
+        .iter()
+        .map(|(key, _)| key.clone())
+        .collect();
+    Ok(Value::new_list(keys))
+}
+
+fn map_values(_vm: &mut VM, args: &[Value]) -> Result<Value, RuntimeError> {
+    let values = as_map(&args[0])
+        .borrow()
+        .get_entries()
+        .iter()
+        .map(|(_, value)| value.clone())
+        .collect();
+    Ok(Value::new_list(values))
+}
+
+fn map_has(_vm: &mut VM, args: &[Value]) -> Result<Value, RuntimeError> {
+    Ok(Value::Bool(as_map(&args[0]).borrow().contains(&args[1])?))
+}
+
+fn map_remove(_vm: &mut VM, args: &[Value]) -> Result<Value, RuntimeError> {
+    Ok(as_map(&args[0]).borrow_mut().remove(&args[1])?)
+}
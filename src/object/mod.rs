@@ -0,0 +1,23 @@
+mod function;
+mod closure;
+mod class;
+mod builtin;
+mod list;
+mod map;
+mod tuple;
+mod string;
+mod enumeration;
+mod native;
+mod module;
+
+pub use function::{ Function, FunctionType };
+pub use closure::{ Closure, Upvalue };
+pub use class::{ BoundMethod, Class, Instance };
+pub use builtin::{ get_builtin_method, is_mutating_method, native_len, BoundBuiltin, BuiltinMethod };
+pub use list::{ List, LIST_METHODS };
+pub use map::{ Map, MAP_METHODS };
+pub use tuple::{ Tuple, TUPLE_METHODS };
+pub use string::{ STRINGS_MODULE, STRING_NATIVES };
+pub use enumeration::{ Enum, EnumVariant, VariantConstructor };
+pub use native::{ NativeFn, NativeFunction };
+pub use module::{ Globals, Module };
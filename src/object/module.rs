@@ -0,0 +1,50 @@
+use std::{ cell::RefCell, collections::{ HashMap, HashSet }, fmt, rc::Rc };
+
+use crate::value::{ Value, ValueType, Variable };
+
+pub type Globals = Rc<RefCell<HashMap<String, Variable>>>;
+
+pub struct Module {
+    name: String,
+    globals: Globals,
+    exports: HashSet<String>,
+}
+
+impl Module {
+    pub fn new(name: String) -> Self {
+        Self::with_globals(name, Rc::new(RefCell::new(HashMap::new())), HashSet::new())
+    }
+
+    pub fn with_globals(name: String, globals: Globals, exports: HashSet<String>) -> Self {
+        Self { name, globals, exports }
+    }
+
+    pub fn get_name(&self) -> &String {
+        &self.name
+    }
+
+    pub fn get(&self, name: &str) -> Option<Value> {
+        if !self.exports.contains(name) {
+            return None;
+        }
+        self.globals.borrow().get(name).map(Variable::get_value)
+    }
+
+    pub fn set(&mut self, name: String, value: Value) {
+        let variable = Variable::new(name.clone(), value, ValueType::Dynamic, false, false);
+        self.globals.borrow_mut().insert(name.clone(), variable);
+        self.exports.insert(name);
+    }
+}
+
+impl fmt::Debug for Module {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<module {}>", self.name)
+    }
+}
+
+impl PartialEq for Module {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self, other)
+    }
+}
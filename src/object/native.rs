@@ -0,0 +1,41 @@
+use std::fmt;
+
+use crate::{ value::Value, vm::{ RuntimeError, VM } };
+
+pub type NativeFn = fn(vm: &mut VM, args: &[Value]) -> Result<Value, RuntimeError>;
+
+pub struct NativeFunction {
+    name: String,
+    arity: usize,
+    function: NativeFn,
+}
+
+impl NativeFunction {
+    pub fn new(name: String, arity: usize, function: NativeFn) -> Self {
+        Self { name, arity, function }
+    }
+
+    pub fn get_name(&self) -> &String {
+        &self.name
+    }
+
+    pub fn get_arity(&self) -> usize {
+        self.arity
+    }
+
+    pub fn call(&self, vm: &mut VM, args: &[Value]) -> Result<Value, RuntimeError> {
+        (self.function)(vm, args)
+    }
+}
+
+impl fmt::Debug for NativeFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<native fn {}>", self.name)
+    }
+}
+
+impl PartialEq for NativeFunction {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self, other)
+    }
+}
@@ -0,0 +1,141 @@
+use crate::{ value::Value, vm::{ RuntimeError, VM } };
+
+use super::NativeFn;
+
+pub const STRINGS_MODULE: &str = "strings";
+
+pub const STRING_NATIVES: [(&str, usize, NativeFn); 14] = [
+    ("len", 1, string_len),
+    ("upper", 1, string_upper),
+    ("lower", 1, string_lower),
+    ("trim", 1, string_trim),
+    ("split", 2, string_split),
+    ("join", 2, string_join),
+    ("contains", 2, string_contains),
+    ("starts_with", 2, string_starts_with),
+    ("replace", 3, string_replace),
+    ("slice", 3, string_slice),
+    ("chars", 1, string_chars),
+    ("to_i32", 1, string_to_i32),
+    ("to_i64", 1, string_to_i64),
+    ("to_f64", 1, string_to_f64),
+];
+
+fn string_arg<'a>(args: &'a [Value], index: usize, method: &str) -> Result<&'a String, String> {
+    match &args[index] {
+        Value::String(string) => Ok(string),
+        other => Err(format!("{} expects a string argument, but got {}", method, other.to_type_string())),
+    }
+}
+
+fn string_len(_vm: &mut VM, args: &[Value]) -> Result<Value, RuntimeError> {
+    Ok(Value::Int64(string_arg(args, 0, "len")?.chars().count() as i64))
+}
+
+fn string_upper(_vm: &mut VM, args: &[Value]) -> Result<Value, RuntimeError> {
+    Ok(Value::String(string_arg(args, 0, "upper")?.to_uppercase()))
+}
+
+fn string_lower(_vm: &mut VM, args: &[Value]) -> Result<Value, RuntimeError> {
+    Ok(Value::String(string_arg(args, 0, "lower")?.to_lowercase()))
+}
+
+fn string_trim(_vm: &mut VM, args: &[Value]) -> Result<Value, RuntimeError> {
+    Ok(Value::String(string_arg(args, 0, "trim")?.trim().to_string()))
+}
+
+fn string_split(_vm: &mut VM, args: &[Value]) -> Result<Value, RuntimeError> {
+    let string = string_arg(args, 0, "split")?;
+    let separator = string_arg(args, 1, "split")?;
+    if separator.is_empty() {
+        return Err(RuntimeError::new("Cannot split on an empty separator, use chars instead"));
+    }
+
+    let parts = string.split(separator.as_str()).map(|part| Value::String(part.to_string()));
+    Ok(Value::new_list(parts.collect()))
+}
+
+fn string_join(_vm: &mut VM, args: &[Value]) -> Result<Value, RuntimeError> {
+    let separator = string_arg(args, 0, "join")?;
+    let Value::List(list) = &args[1] else {
+        return Err(format!("join expects a list argument, but got {}", args[1].to_type_string()).into());
+    };
+
+    let parts = list
+        .borrow()
+        .get_items()
+        .iter()
+        .map(|item| {
+            if item.is_printable() {
+                Ok(item.to_string())
+            } else {
+                Err(format!("Cannot join {}", item.to_type_string()))
+            }
+        })
+        .collect::<Result<Vec<String>, String>>()?;
+
+    Ok(Value::String(parts.join(separator)))
+}
+
+fn string_contains(_vm: &mut VM, args: &[Value]) -> Result<Value, RuntimeError> {
+    let string = string_arg(args, 0, "contains")?;
+    let pattern = string_arg(args, 1, "contains")?;
+    Ok(Value::Bool(string.contains(pattern.as_str())))
+}
+
+fn string_starts_with(_vm: &mut VM, args: &[Value]) -> Result<Value, RuntimeError> {
+    let string = string_arg(args, 0, "starts_with")?;
+    let prefix = string_arg(args, 1, "starts_with")?;
+    Ok(Value::Bool(string.starts_with(prefix.as_str())))
+}
+
+fn string_replace(_vm: &mut VM, args: &[Value]) -> Result<Value, RuntimeError> {
+    let string = string_arg(args, 0, "replace")?;
+    let from = string_arg(args, 1, "replace")?;
+    let to = string_arg(args, 2, "replace")?;
+    if from.is_empty() {
+        return Err(RuntimeError::new("Cannot replace an empty string"));
+    }
+
+    Ok(Value::String(string.replace(from.as_str(), to)))
+}
+
+fn string_slice(_vm: &mut VM, args: &[Value]) -> Result<Value, RuntimeError> {
+    let string = string_arg(args, 0, "slice")?;
+    let start = args[1].as_index()?;
+    let end = args[2].as_index()?;
+    let length = string.chars().count();
+
+    if start > end || end > length {
+        return Err(
+            format!("Cannot slice {}..{} of a string of length {}", start, end, length).into()
+        );
+    }
+
+    Ok(Value::String(string.chars().skip(start).take(end - start).collect()))
+}
+
+fn string_chars(_vm: &mut VM, args: &[Value]) -> Result<Value, RuntimeError> {
+    let chars = string_arg(args, 0, "chars")?.chars().map(|c| Value::String(c.to_string()));
+    Ok(Value::new_list(chars.collect()))
+}
+
+fn parse_number<T: std::str::FromStr>(args: &[Value], type_name: &str) -> Result<T, RuntimeError> {
+    let string = string_arg(args, 0, &format!("to_{}", type_name))?;
+    string
+        .trim()
+        .parse()
+        .map_err(|_| format!("Cannot parse {:?} as {}", string, type_name).into())
+}
+
+fn string_to_i32(_vm: &mut VM, args: &[Value]) -> Result<Value, RuntimeError> {
+    Ok(Value::Int32(parse_number(args, "i32")?))
+}
+
+fn string_to_i64(_vm: &mut VM, args: &[Value]) -> Result<Value, RuntimeError> {
+    Ok(Value::Int64(parse_number(args, "i64")?))
+}
+
+fn string_to_f64(_vm: &mut VM, args: &[Value]) -> Result<Value, RuntimeError> {
+    Ok(Value::Float64(parse_number(args, "f64")?))
+}
@@ -0,0 +1,40 @@
+use crate::{ value::Value, vm::{ RuntimeError, VM } };
+
+use super::BuiltinMethod;
+
+#[derive(Debug, PartialEq)]
+pub struct Tuple {
+    items: Vec<Value>,
+}
+
+impl Tuple {
+    pub fn new(items: Vec<Value>) -> Self {
+        Self { items }
+    }
+
+    pub fn get_items(&self) -> &Vec<Value> {
+        &self.items
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn get(&self, index: usize) -> Result<Value, String> {
+        self.items
+            .get(index)
+            .cloned()
+            .ok_or_else(|| {
+                format!("Index {} is out of bounds for a tuple of length {}", index, self.items.len())
+            })
+    }
+}
+
+pub const TUPLE_METHODS: [BuiltinMethod; 1] = [BuiltinMethod::new("len", 0, false, tuple_len)];
+
+fn tuple_len(_vm: &mut VM, args: &[Value]) -> Result<Value, RuntimeError> {
+    match &args[0] {
+        Value::Tuple(tuple) => Ok(Value::Int64(tuple.len() as i64)),
+        _ => unreachable!("Tuple methods are only bound to tuples"),
+    }
+}
@@ -10,6 +10,7 @@ pub struct Parser<'a> {
     had_error: bool,
     panic_mode: bool,
     can_declare: bool,
+    file: Option<String>,
 }
 
 impl<'a> Parser<'a> {
@@ -26,9 +27,18 @@ impl<'a> Parser<'a> {
             had_error: false,
             panic_mode: false,
             can_declare: true,
+            file: None,
         }
     }
 
+    pub fn set_file(&mut self, file: String) {
+        self.file = Some(file);
+    }
+
+    pub fn get_file(&self) -> Option<&String> {
+        self.file.as_ref()
+    }
+
     pub fn set_saved_token(&mut self, token: Token) {
         self.saved_token = Some(token);
     }
@@ -63,6 +73,18 @@ impl<'a> Parser<'a> {
         self.panic_mode = mode;
     }
 
+    pub fn set_had_error(&mut self) {
+        self.had_error = true;
+        self.panic_mode = true;
+    }
+
+    fn location(&self, line: usize) -> String {
+        match &self.file {
+            Some(file) => format!("line {} of {}", line, file),
+            None => format!("line {}", line),
+        }
+    }
+
     pub fn advance(&mut self) {
         if let Some(previous) = &self.previous {
             self.double_previous_type = Some(previous.get_token_type().clone());
@@ -103,12 +125,21 @@ impl<'a> Parser<'a> {
         self.panic_mode = true;
         if let Some(token) = token {
             let line = token.get_line();
-            eprintln!("Error: {}, at: line {}", msg, line)
+            eprintln!("Error: {}, at: {}", msg, self.location(line))
         } else {
             eprintln!("Error: {}", msg)
         }
     }
 
+    pub fn report_error_at_line(&mut self, msg: &String, line: usize) {
+        if self.panic_mode {
+            return;
+        }
+        self.had_error = true;
+        self.panic_mode = true;
+        eprintln!("Error: {}, at: {}", msg, self.location(line))
+    }
+
     pub fn report_error_at_saved_token(&mut self, msg: &String) {
         if self.panic_mode {
             return;
@@ -117,7 +148,7 @@ impl<'a> Parser<'a> {
         self.panic_mode = true;
         let token = self.saved_token.as_ref().unwrap();
         let line = token.get_line();
-        eprintln!("Error: {}{}, at: line {}", msg, token.get_token_type().as_str(), line)
+        eprintln!("Error: {}{}, at: {}", msg, token.get_token_type().as_str(), self.location(line))
     }
 
     // self.current = Some(self.lexer.scan_token());
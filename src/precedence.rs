@@ -2,12 +2,14 @@
 pub enum Precedence {
     PrecNone = 0,
     PrecAssignment,
+    PrecCoalesce,
     PrecOr,
     PrecAnd,
     PrecEquality,
     PrecComparison,
     PrecTerm,
     PrecFactor,
+    PrecCast,
     PrecUnary,
     PrecCall,
     PrecPrimary,
@@ -18,15 +20,17 @@ impl From<usize> for Precedence {
         match value {
             0 => Precedence::PrecNone,
             1 => Precedence::PrecAssignment,
-            2 => Precedence::PrecOr,
-            3 => Precedence::PrecAnd,
-            4 => Precedence::PrecEquality,
-            5 => Precedence::PrecComparison,
-            6 => Precedence::PrecTerm,
-            7 => Precedence::PrecFactor,
-            8 => Precedence::PrecUnary,
-            9 => Precedence::PrecCall,
-            10 => Precedence::PrecPrimary,
+            2 => Precedence::PrecCoalesce,
+            3 => Precedence::PrecOr,
+            4 => Precedence::PrecAnd,
+            5 => Precedence::PrecEquality,
+            6 => Precedence::PrecComparison,
+            7 => Precedence::PrecTerm,
+            8 => Precedence::PrecFactor,
+            9 => Precedence::PrecCast,
+            10 => Precedence::PrecUnary,
+            11 => Precedence::PrecCall,
+            12 => Precedence::PrecPrimary,
             _ => panic!("Invalid precedence"),
         }
     }
@@ -37,15 +41,17 @@ impl From<Precedence> for usize {
         match value {
             Precedence::PrecNone => 0,
             Precedence::PrecAssignment => 1,
-            Precedence::PrecOr => 2,
-            Precedence::PrecAnd => 3,
-            Precedence::PrecEquality => 4,
-            Precedence::PrecComparison => 5,
-            Precedence::PrecTerm => 6,
-            Precedence::PrecFactor => 7,
-            Precedence::PrecUnary => 8,
-            Precedence::PrecCall => 9,
-            Precedence::PrecPrimary => 10,
+            Precedence::PrecCoalesce => 2,
+            Precedence::PrecOr => 3,
+            Precedence::PrecAnd => 4,
+            Precedence::PrecEquality => 5,
+            Precedence::PrecComparison => 6,
+            Precedence::PrecTerm => 7,
+            Precedence::PrecFactor => 8,
+            Precedence::PrecCast => 9,
+            Precedence::PrecUnary => 10,
+            Precedence::PrecCall => 11,
+            Precedence::PrecPrimary => 12,
         }
     }
 }
@@ -0,0 +1,134 @@
+#[cfg(test)]
+mod test {
+    use crate::{ value::Value, vm::{ InterpretResult, VM } };
+
+    #[test]
+    fn test_numeric_casts() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret("5 as i64");
+        assert_eq!(result, InterpretResult::Debug(Value::Int64(5)));
+        vm.free_vm();
+
+        let result = vm.interpret("5 as f64");
+        assert_eq!(result, InterpretResult::Debug(Value::Float64(5.0)));
+        vm.free_vm();
+
+        let result = vm.interpret("3.0 as i8");
+        assert_eq!(result, InterpretResult::Debug(Value::Int8(3)));
+        vm.free_vm();
+
+        let result = vm.interpret("255 as u8");
+        assert_eq!(result, InterpretResult::Debug(Value::UnsignedInt8(255)));
+        vm.free_vm();
+
+        let result = vm.interpret("1.5 as f32");
+        assert_eq!(result, InterpretResult::Debug(Value::Float32(1.5)));
+        vm.free_vm();
+
+        let result = vm.interpret("i64 x := 7\nx as i32");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(7)));
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_lossy_casts() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret("1.5 as i32");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret("300 as u8");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret("-1 as u32");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret("3000000000 as i32");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret("1000000000000000000000000000000000000000.0 as f32");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_string_casts() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret("42 as string");
+        assert_eq!(result, InterpretResult::Debug(Value::String("42".to_string())));
+        vm.free_vm();
+
+        let result = vm.interpret("true as string");
+        assert_eq!(result, InterpretResult::Debug(Value::String("true".to_string())));
+        vm.free_vm();
+
+        let result = vm.interpret("\"42\" as i16");
+        assert_eq!(result, InterpretResult::Debug(Value::Int16(42)));
+        vm.free_vm();
+
+        let result = vm.interpret("\"2.5\" as f64");
+        assert_eq!(result, InterpretResult::Debug(Value::Float64(2.5)));
+        vm.free_vm();
+
+        let result = vm.interpret("\"2.5\" as i32");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret("\"abc\" as i32");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_cast_precedence() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret("2 * 3 as f64");
+        assert_eq!(result, InterpretResult::Debug(Value::Float64(6.0)));
+        vm.free_vm();
+
+        let result = vm.interpret("1 + 2 as i64 + 3");
+        assert_eq!(result, InterpretResult::Debug(Value::Int64(6)));
+        vm.free_vm();
+
+        let result = vm.interpret("-2.0 as i8");
+        assert_eq!(result, InterpretResult::Debug(Value::Int8(-2)));
+        vm.free_vm();
+
+        let result = vm.interpret("(7 ~/ 2) as f32");
+        assert_eq!(result, InterpretResult::Debug(Value::Float32(3.0)));
+        vm.free_vm();
+
+        let result = vm.interpret("5 as");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_cast_type_checking() {
+        let mut vm = VM::new();
+        vm.set_type_checking(true);
+
+        let result = vm.interpret("f64 x := 1.5\ni32 y := x as i32");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret("i32 y := 2.0 as i32\ny");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(2)));
+        vm.free_vm();
+
+        let result = vm.interpret("f64 x := 1.5\ni32 y := x");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("fn f() {}\nf as i32");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+    }
+}
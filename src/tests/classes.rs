@@ -0,0 +1,103 @@
+#[cfg(test)]
+mod test {
+    use crate::{ value::Value, vm::{ InterpretResult, VM } };
+
+    #[test]
+    fn test_fields() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret("class Empty {}\ne := Empty()\ne.name = \"hi\"\ne.name");
+        assert_eq!(result, InterpretResult::Debug(Value::String("hi".to_string())));
+        vm.free_vm();
+
+        let result = vm.interpret("class Empty {}\ne := Empty()\ne.missing");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret("x := 1\nx.field = 2");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_methods_and_initializer() {
+        let mut vm = VM::new();
+
+        let class_source =
+            "class Point {\n fn init(x, y) {\n this.x = x\n this.y = y\n }\n fn sum() {\n return this.x + this.y\n }\n}\n";
+
+        let result = vm.interpret(&format!("{}p := Point(1, 2)\np.sum()", class_source));
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(3)));
+        vm.free_vm();
+
+        let result = vm.interpret(&format!("{}p := Point(1, 2)\np.x = 10\nsum := p.sum\nsum()", class_source));
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(12)));
+        vm.free_vm();
+
+        let result = vm.interpret(&format!("{}Point(1)", class_source));
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_this() {
+        let mut vm = VM::new();
+
+        let source =
+            "class Counter {\n fn init() { this.count = 0 }\n fn incrementer() {\n return fn() {\n this.count = this.count + 1\n return this.count\n }\n }\n}\nc := Counter()\ni := c.incrementer()\ni()\ni()";
+        let result = vm.interpret(source);
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(2)));
+        vm.free_vm();
+
+        let result = vm.interpret("this");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("class A {\n fn init() { return 1 }\n}");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_inheritance() {
+        let mut vm = VM::new();
+
+        let class_source =
+            "class User {\n fn init(name) { this.name = name }\n fn greet() { return \"Hi \" + this.name }\n fn role() { return \"user\" }\n}\nclass Admin < User {\n fn init(name, level) {\n super.init(name)\n this.level = level\n }\n fn role() { return \"admin/\" + super.role() }\n}\n";
+
+        let result = vm.interpret(&format!("{}Admin(\"bob\", 3).greet()", class_source));
+        assert_eq!(result, InterpretResult::Debug(Value::String("Hi bob".to_string())));
+        vm.free_vm();
+
+        let result = vm.interpret(&format!("{}Admin(\"bob\", 3).role()", class_source));
+        assert_eq!(result, InterpretResult::Debug(Value::String("admin/user".to_string())));
+        vm.free_vm();
+
+        let result = vm.interpret(&format!("{}Admin(\"bob\", 3).level", class_source));
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(3)));
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_inheritance_errors() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret("x := 1\nclass Bad < x {}");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret(
+            "class A {}\nclass B < A {\n fn call() { return super.missing() }\n}\nB().call()"
+        );
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret("class A < A {}");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("class A {\n fn call() { return super.call() }\n}");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+    }
+}
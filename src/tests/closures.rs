@@ -0,0 +1,62 @@
+#[cfg(test)]
+mod test {
+    use crate::{ value::Value, vm::{ InterpretResult, VM } };
+
+    #[test]
+    fn test_captured_counter() {
+        let mut vm = VM::new();
+
+        let source =
+            "fn make_counter() {\n mut count := 0\n fn increment() {\n count = count + 1\n return count\n }\n return increment\n}\nc := make_counter()\nc()\nc()\nc()";
+        let result = vm.interpret(source);
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(3)));
+        vm.free_vm();
+
+        let source =
+            "fn make_counter() {\n mut count := 0\n return fn() {\n count = count + 1\n return count\n }\n}\na := make_counter()\nb := make_counter()\na()\na()\nb()";
+        let result = vm.interpret(source);
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(1)));
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_nested_upvalues() {
+        let mut vm = VM::new();
+
+        let source =
+            "fn outer() {\n mut x := \"before\"\n fn middle() {\n fn inner() { x = \"after\" }\n return inner\n }\n middle()()\n return x\n}\nouter()";
+        let result = vm.interpret(source);
+        assert_eq!(result, InterpretResult::Debug(Value::String("after".to_string())));
+        vm.free_vm();
+
+        let result = vm.interpret(
+            "mut r := 0\n{\n fn count(n) {\n if n == 0 { return 0 }\n return 1 + count(n - 1)\n }\n r = count(4)\n}\nr"
+        );
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(4)));
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_closed_loop_variables() {
+        let mut vm = VM::new();
+
+        let source =
+            "mut captured := null\nfor i in 0..3 {\n j := i * 10\n if i == 1 { captured = fn() { return j } }\n}\ncaptured()";
+        let result = vm.interpret(source);
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(10)));
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_immutable_capture() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret("fn outer() {\n count := 0\n return fn() { count = 1 }\n}");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("fn outer() {\n count := 5\n return fn() { return count }\n}\nouter()()");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(5)));
+        vm.free_vm();
+    }
+}
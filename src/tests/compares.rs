@@ -83,4 +83,82 @@ mod test {
         assert_eq!(result, InterpretResult::Debug(Value::Bool(true)));
         vm.free_vm();
     }
+
+    #[test]
+    fn compare_across_numeric_types() {
+        use crate::{ value::Value, vm::{ InterpretResult, VM } };
+
+        let mut vm = VM::new();
+        let result = vm.interpret("i64 x := 1\nx == 1");
+        assert_eq!(result, InterpretResult::Debug(Value::Bool(true)));
+        vm.free_vm();
+
+        let result = vm.interpret("u8 x := 3\nx == 3.0");
+        assert_eq!(result, InterpretResult::Debug(Value::Bool(true)));
+        vm.free_vm();
+
+        let result = vm.interpret("f32 x := 0.5\nx != 0.5");
+        assert_eq!(result, InterpretResult::Debug(Value::Bool(false)));
+        vm.free_vm();
+
+        let result = vm.interpret("i8 x := -1\nu64 y := 1\nx < y");
+        assert_eq!(result, InterpretResult::Debug(Value::Bool(true)));
+        vm.free_vm();
+
+        let result = vm.interpret("3 > 2.5");
+        assert_eq!(result, InterpretResult::Debug(Value::Bool(true)));
+        vm.free_vm();
+
+        let result = vm.interpret("2 >= 2.5");
+        assert_eq!(result, InterpretResult::Debug(Value::Bool(false)));
+        vm.free_vm();
+
+        let result = vm.interpret("9007199254740993 == 9007199254740992.0");
+        assert_eq!(result, InterpretResult::Debug(Value::Bool(false)));
+        vm.free_vm();
+    }
+
+    #[test]
+    fn compare_strings() {
+        use crate::{ value::Value, vm::{ InterpretResult, VM } };
+
+        let mut vm = VM::new();
+        let result = vm.interpret("\"apple\" < \"banana\"");
+        assert_eq!(result, InterpretResult::Debug(Value::Bool(true)));
+        vm.free_vm();
+
+        let result = vm.interpret("\"b\" >= \"ab\"");
+        assert_eq!(result, InterpretResult::Debug(Value::Bool(true)));
+        vm.free_vm();
+
+        let result = vm.interpret("\"a\" == \"a\"");
+        assert_eq!(result, InterpretResult::Debug(Value::Bool(true)));
+        vm.free_vm();
+    }
+
+    #[test]
+    fn compare_incomparable_values() {
+        use crate::{ value::Value, vm::{ InterpretResult, VM } };
+
+        let mut vm = VM::new();
+        let result = vm.interpret("\"a\" < 5");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret("true > false");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret("null <= 1");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret("\"1\" == 1");
+        assert_eq!(result, InterpretResult::Debug(Value::Bool(false)));
+        vm.free_vm();
+
+        let result = vm.interpret("null != false");
+        assert_eq!(result, InterpretResult::Debug(Value::Bool(true)));
+        vm.free_vm();
+    }
 }
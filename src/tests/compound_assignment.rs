@@ -0,0 +1,176 @@
+#[cfg(test)]
+mod test {
+    use crate::{ compiler::Compiler, opcodes::OpCode, value::Value, vm::{ InterpretResult, VM } };
+
+    #[test]
+    fn test_compound_assignment_on_variables() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret("mut x := 1\nx += 2\nx *= 3\nx -= 1\nx ^= 2\nx");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(64)));
+        vm.free_vm();
+
+        let result = vm.interpret("mut x := 9\nx /= 2\nx");
+        assert_eq!(result, InterpretResult::Debug(Value::Float64(4.5)));
+        vm.free_vm();
+
+        let result = vm.interpret("mut s := \"a\"\ns += \"b\"\ns");
+        assert_eq!(result, InterpretResult::Debug(Value::String("ab".to_string())));
+        vm.free_vm();
+
+        let result = vm.interpret("fn f() {\nmut total := 10\ntotal -= 4\nreturn total\n}\nf()");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(6)));
+        vm.free_vm();
+
+        let result = vm.interpret("mut x := 1\ny := x += 1\ny");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(2)));
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_increment_and_decrement() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret("mut i := 0\ni++\ni++\ni--\ni");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(1)));
+        vm.free_vm();
+
+        let result = vm.interpret(
+            "fn counter() {\nmut count := 0\nreturn fn() {\ncount++\nreturn count\n}\n}\nc := counter()\nc()\nc()"
+        );
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(2)));
+        vm.free_vm();
+
+        let result = vm.interpret("mut i8 small := 126\nsmall++\nsmall");
+        assert_eq!(result, InterpretResult::Debug(Value::Int8(127)));
+        vm.free_vm();
+
+        let result = vm.interpret("mut i8 small := 127\nsmall++");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret("mut f64 x := 1.5\nx++\nx");
+        assert_eq!(result, InterpretResult::Debug(Value::Float64(2.5)));
+        vm.free_vm();
+
+        let result = vm.interpret("a := 5\nb := 2\na--b");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(7)));
+        vm.free_vm();
+
+        let result = vm.interpret("x := 3\ny := --x\ny");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(3)));
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_compound_assignment_on_globals() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret("mut count := 1\nfn bump() {\ncount += 2\ncount++\n}\nbump()\ncount");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(4)));
+        vm.free_vm();
+
+        let result = vm.interpret("mut u8 small := 250\nsmall += 10");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret("missing += 1");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let function = Compiler::new("mut x := 1\nx += 2").compile().unwrap();
+        let code = function.get_chunk().get_code();
+        let mut instructions = Vec::new();
+        let mut offset = 0;
+        while offset < code.len() {
+            let opcode = OpCode::from(code[offset]);
+            offset += match opcode {
+                OpCode::OpConstant | OpCode::OpDefineGlobal => 2,
+                OpCode::OpUpdateGlobal => 3,
+                OpCode::OpPop | OpCode::OpNull | OpCode::OpReturn => 1,
+                _ => panic!("Unexpected instruction {:?}", opcode),
+            };
+            instructions.push(format!("{:?}", opcode));
+        }
+        assert_eq!(
+            instructions,
+            ["OpConstant", "OpDefineGlobal", "OpConstant", "OpUpdateGlobal", "OpPop", "OpNull", "OpReturn"]
+        );
+    }
+
+    #[test]
+    fn test_compound_assignment_on_fields_and_elements() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret(
+            "class P { fn init() { this.n = 1 } }\np := P()\np.n += 10\np.n++\np.n"
+        );
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(12)));
+        vm.free_vm();
+
+        let result = vm.interpret("mut xs := [1, 2, 3]\nxs[1] += 40\nxs[2]++\nxs as string");
+        assert_eq!(result, InterpretResult::Debug(Value::String("[1, 42, 4]".to_string())));
+        vm.free_vm();
+
+        let result = vm.interpret("mut m := { \"a\": 2 }\nm[\"a\"] ^= 3\nm[\"a\"]");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(8)));
+        vm.free_vm();
+
+        let result = vm.interpret(
+            "mut calls := 0\nfn next() {\ncalls++\nreturn 0\n}\nmut xs := [5]\nxs[next()] += 1\ncalls"
+        );
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(1)));
+        vm.free_vm();
+
+        let result = vm.interpret("mut m := { \"a\": 2 }\nm[\"b\"] += 1");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_compound_assignment_on_immutable_bindings() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret("x := 1\nx += 1");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret("x := 1\nx = 2");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret("{\ni := 0\ni++\n}");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("fn f() {\ncount := 0\nreturn fn() { count-- }\n}");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("xs := [1]\nxs[0] += 1");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("mut a := 1\nmut b := 2\na + b += 1");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_compound_assignment_types() {
+        let mut vm = VM::new();
+        vm.set_type_checking(true);
+
+        let result = vm.interpret("mut i64 x := 1\nx += \"a\"");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("mut i64 x := 1\nx /= 2");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("mut i64 x := 1\nx *= 5\nx");
+        assert_eq!(result, InterpretResult::Debug(Value::Int64(5)));
+        vm.free_vm();
+    }
+}
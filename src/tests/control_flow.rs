@@ -0,0 +1,103 @@
+#[cfg(test)]
+mod test {
+    use crate::{ value::Value, vm::{ InterpretResult, VM } };
+
+    #[test]
+    fn test_if_else() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret("mut r := 0\nif 1 < 2 {\n r = 1\n} else {\n r = 2\n}\nr");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(1)));
+        vm.free_vm();
+
+        let result = vm.interpret("mut r := 0\nif 1 > 2 {\n r = 1\n} else {\n r = 2\n}\nr");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(2)));
+        vm.free_vm();
+
+        let result = vm.interpret("mut r := 0\nif null { r = 1 }\nr");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(0)));
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_else_if_chain() {
+        let mut vm = VM::new();
+
+        let source =
+            "x := 7\nmut r := \"none\"\nif x < 5 {\n r = \"small\"\n} else if x < 10 {\n r = \"medium\"\n} else {\n r = \"large\"\n}\nr";
+        let result = vm.interpret(source);
+        assert_eq!(result, InterpretResult::Debug(Value::String("medium".to_string())));
+        vm.free_vm();
+
+        let result = vm.interpret("if true {\n x := 1\n} else 2");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_while_loop() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret("mut i := 0\nmut sum := 0\nwhile i < 5 {\n sum = sum + i\n i = i + 1\n}\nsum");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(10)));
+        vm.free_vm();
+
+        let result = vm.interpret("mut i := 0\nwhile true {\n i = i + 1\n if i == 3 { break }\n}\ni");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(3)));
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_for_loop() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret("mut sum := 0\nfor i in 0..5 {\n sum = sum + i\n}\nsum");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(10)));
+        vm.free_vm();
+
+        let result = vm.interpret(
+            "mut sum := 0\nfor i in 0..10 {\n if i == 2 { continue }\n if i == 5 { break }\n sum = sum + i\n}\nsum"
+        );
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(8)));
+        vm.free_vm();
+
+        let result = vm.interpret("for i in 0..2 {}\ni");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret(
+            "mut fs := []\nfor i in 0..3 {\n fs.push(fn() { return i })\n}\nfs[0]() + fs[1]() * 10 + fs[2]() * 100"
+        );
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(210)));
+        vm.free_vm();
+
+        let result = vm.interpret(
+            "mut fs := []\nfor i in 0..4 {\n f := fn() { return i }\n if i % 2 == 0 { continue }\n fs.push(f)\n}\nfs[0]() + fs[1]()"
+        );
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(4)));
+        vm.free_vm();
+
+        let result = vm.interpret("mut fs := []\nfor x in [1, 2] {\n fs.push(fn() { return x })\n}\nfs[0]()");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(1)));
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_labelled_loops() {
+        let mut vm = VM::new();
+
+        let source =
+            "mut count := 0\n'outer: for a in 0..3 {\n for b in 0..3 {\n if b == 2 { continue 'outer }\n if a == 2 { break 'outer }\n count = count + 1\n }\n}\ncount";
+        let result = vm.interpret(source);
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(4)));
+        vm.free_vm();
+
+        let result = vm.interpret("break");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("while true {\n break 'missing\n}");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+    }
+}
@@ -0,0 +1,187 @@
+#[cfg(test)]
+mod test {
+    use crate::{ value::Value, vm::{ InterpretResult, VM } };
+
+    #[test]
+    fn test_enum_variants() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret("enum Shape { Circle(f64), Rect(f64, f64), Empty }\nShape.Rect(1, 2.5) as string");
+        assert_eq!(result, InterpretResult::Debug(Value::String("Shape.Rect(1, 2.5)".to_string())));
+        vm.free_vm();
+
+        let result = vm.interpret("enum Shape { Circle(f64), Rect(f64, f64), Empty }\n\"{Shape.Empty}\"");
+        assert_eq!(result, InterpretResult::Debug(Value::String("Shape.Empty".to_string())));
+        vm.free_vm();
+
+        let result = vm.interpret("enum Shape { Circle(f64), Rect(f64, f64), Empty }\n\"{Shape.Circle}\"");
+        assert_eq!(result, InterpretResult::Debug(Value::String("<fn Shape.Circle>".to_string())));
+        vm.free_vm();
+
+        let result = vm.interpret("enum Shape { Circle(f64), Rect(f64, f64), Empty }\nShape.Circle(1) == Shape.Circle(1.0)");
+        assert_eq!(result, InterpretResult::Debug(Value::Bool(true)));
+        vm.free_vm();
+
+        let result = vm.interpret("enum Shape { Circle(f64), Rect(f64, f64), Empty }\nShape.Circle(1) == Shape.Circle(2)");
+        assert_eq!(result, InterpretResult::Debug(Value::Bool(false)));
+        vm.free_vm();
+
+        let result = vm.interpret("enum Color { Red, Green }\nColor.Red != Color.Green and Color.Red == Color.Red");
+        assert_eq!(result, InterpretResult::Debug(Value::Bool(true)));
+        vm.free_vm();
+
+        let result = vm.interpret("enum Shape { Circle(f64), Rect(f64, f64), Empty }\nShape.Rect(1, 2)[1]");
+        assert_eq!(result, InterpretResult::Debug(Value::Float64(2.0)));
+        vm.free_vm();
+
+        let result = vm.interpret("enum Shape { Circle(f64), Rect(f64, f64), Empty }\nShape.Square");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret("enum Shape { Circle(f64), Rect(f64, f64), Empty }\nShape.Circle(1, 2)");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret("enum Shape { Circle(f64), Rect(f64, f64), Empty }\nShape.Circle(\"x\")");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret("enum Shape { Circle(f64), Rect(f64, f64), Empty }\nShape = 1");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("enum A { X, X }");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("enum A { X(foo) }");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_enum_match() {
+        let mut vm = VM::new();
+
+        let area =
+            "enum Shape { Circle(f64), Rect(f64, f64), Empty }
+            enum Color { Red, Green }
+            fn area(s) {
+                return match s {
+                    Shape.Circle(r) => 3.0 * r * r,
+                    Shape.Rect(w, h) if w == h => \"square\",
+                    Shape.Rect(w, h) => w * h,
+                    Shape.Empty => 0,
+                }
+            }
+            ";
+
+        let result = vm.interpret(&format!("{}area(Shape.Circle(2))", area));
+        assert_eq!(result, InterpretResult::Debug(Value::Float64(12.0)));
+        vm.free_vm();
+
+        let result = vm.interpret(&format!("{}area(Shape.Rect(2, 3.5))", area));
+        assert_eq!(result, InterpretResult::Debug(Value::Float64(7.0)));
+        vm.free_vm();
+
+        let result = vm.interpret(&format!("{}area(Shape.Rect(2, 2))", area));
+        assert_eq!(result, InterpretResult::Debug(Value::String("square".to_string())));
+        vm.free_vm();
+
+        let result = vm.interpret(&format!("{}area(Shape.Empty)", area));
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(0)));
+        vm.free_vm();
+
+        let result = vm.interpret(&format!("{}area(Color.Red)", area));
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret("enum Color { Red, Green }\nmatch Color.Green { Color.Red | Color.Green => \"color\" }");
+        assert_eq!(result, InterpretResult::Debug(Value::String("color".to_string())));
+        vm.free_vm();
+
+        let result = vm.interpret("enum Shape { Circle(f64), Rect(f64, f64), Empty }\nmatch (Shape.Circle(2), 1) { (Shape.Circle(r), n) => r + n, _ => 0 }");
+        assert_eq!(result, InterpretResult::Debug(Value::Float64(3.0)));
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_enum_match_errors() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret("enum Shape { Circle(f64), Rect(f64, f64), Empty }\nmatch Shape.Empty { Shape.Circle(r) => 1, Shape.Empty => 2 }");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("enum Shape { Circle(f64), Rect(f64, f64), Empty }\nmatch Shape.Empty { Shape.Circle(r) => 1, Shape.Rect(w, h) if w > 1 => 2, Shape.Empty => 3 }");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("enum Shape { Circle(f64), Rect(f64, f64), Empty }\nmatch Shape.Empty { Shape.Square => 1, _ => 2 }");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("enum Shape { Circle(f64), Rect(f64, f64), Empty }\nmatch Shape.Empty { Shape.Circle(a, b) => 1, _ => 2 }");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_enum_typed_variables() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret("enum Shape { Circle(f64), Rect(f64, f64), Empty }\nShape s := Shape.Rect(1, 2)\ns as string");
+        assert_eq!(result, InterpretResult::Debug(Value::String("Shape.Rect(1, 2)".to_string())));
+        vm.free_vm();
+
+        let result = vm.interpret("enum Shape { Circle(f64), Rect(f64, f64), Empty }\nmut Shape s := Shape.Empty\ns = Shape.Circle(3)\nmut r := \"\"\n{\nmut Shape t := s\nt = Shape.Empty\nr = \"{s} {t}\"\n}\nr");
+        assert_eq!(result, InterpretResult::Debug(Value::String("Shape.Circle(3) Shape.Empty".to_string())));
+        vm.free_vm();
+
+        let result = vm.interpret("enum Shape { Circle(f64), Rect(f64, f64), Empty }\nShape s := 5");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("enum Shape { Circle(f64), Rect(f64, f64), Empty }\nenum Color { Red, Green }\nShape s := Color.Red");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret("enum Shape { Circle(f64), Rect(f64, f64), Empty }\nenum Color { Red, Green }\nmut Shape s := Shape.Empty\ns = Color.Red");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret("enum Shape { Circle(f64), Rect(f64, f64), Empty }\nenum Color { Red, Green }\nfn f() {\nmut Shape s := Shape.Empty\ns = Color.Red\n}\nf()");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret("enum Shape { Circle(f64), Rect(f64, f64), Empty }\nShape s");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("enum Shape { Circle(f64), Rect(f64, f64), Empty }\nfn other() {\nenum Shape { Empty }\nreturn Shape.Empty\n}\nShape s := other()");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_enum_type_checking() {
+        let mut vm = VM::new();
+        vm.set_type_checking(true);
+
+        let result = vm.interpret("enum Shape { Circle(f64), Rect(f64, f64), Empty }\nenum Color { Red, Green }\nShape s := Shape.Empty\nColor c := s");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("enum Shape { Circle(f64), Rect(f64, f64), Empty }\nShape s := Shape.Empty\nShape t := s\nt == s");
+        assert_eq!(result, InterpretResult::Debug(Value::Bool(true)));
+        vm.free_vm();
+
+        let result = vm.interpret("enum Shape { Circle(f64), Rect(f64, f64), Empty }\nShape()");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("enum Shape { Circle(f64), Rect(f64, f64), Empty }\nfn f(x) {\nreturn x\n}\nf(Shape) == Shape");
+        assert_eq!(result, InterpretResult::Debug(Value::Bool(true)));
+        vm.free_vm();
+    }
+}
@@ -0,0 +1,68 @@
+#[cfg(test)]
+mod test {
+    use crate::{ value::Value, vm::{ InterpretResult, VM } };
+
+    #[test]
+    fn test_function_call() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret("fn add(a, b) {\n return a + b\n}\nadd(1, 2)");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(3)));
+        vm.free_vm();
+
+        let result = vm.interpret("fn nothing() {}\nnothing()");
+        assert_eq!(result, InterpretResult::Debug(Value::Null));
+        vm.free_vm();
+
+        let result = vm.interpret("square := fn(x) { return x * x }\nsquare(7)");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(49)));
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_recursion() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret(
+            "fn fib(n) {\n if n < 2 { return n }\n return fib(n - 1) + fib(n - 2)\n}\nfib(10)"
+        );
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(55)));
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_typed_mutable_parameters() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret(
+            "fn increment(mut i32 prev, mut i64 out) {\n prev = prev + 1\n return prev + out\n}\nincrement(1, 10)"
+        );
+        assert_eq!(result, InterpretResult::Debug(Value::Int64(12)));
+        vm.free_vm();
+
+        let result = vm.interpret("fn set(prev) {\n prev = 2\n}");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_call_errors() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret("fn add(a, b) {\n return a + b\n}\nadd(1)");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret("x := 1\nx()");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret("fn forever() {\n return forever()\n}\nforever()");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret("return 1");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+    }
+}
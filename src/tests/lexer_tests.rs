@@ -98,6 +98,29 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_keyword_prefixes() {
+        use crate::lexer::Lexer;
+
+        let source = "fn fname if iffy i8 i8s";
+        let mut lexer = Lexer::new(source);
+
+        let (token_names, _) = lexer.get_token_names_and_lexemes_vec();
+
+        assert_eq!(
+            token_names,
+            vec![
+                String::from("function"),
+                String::from("identifier"),
+                String::from("if"),
+                String::from("identifier"),
+                String::from("i8"),
+                String::from("identifier"),
+                String::from("end of file")
+            ]
+        );
+    }
+
     #[test]
     fn test_variable_assignment() {
         use crate::lexer::Lexer;
@@ -122,4 +145,164 @@ mod test {
             vec![String::from("hello"), String::from(":="), String::from("2"), String::from("")]
         )
     }
+
+    #[test]
+    fn test_labelled_for_range() {
+        use crate::lexer::Lexer;
+
+        let source = "'outer: for i in 0..n";
+        let mut lexer = Lexer::new(source);
+
+        let (token_names, token_lexemes) = lexer.get_token_names_and_lexemes_vec();
+
+        assert_eq!(
+            token_names,
+            vec![
+                String::from("label"),
+                String::from("colon"),
+                String::from("for"),
+                String::from("identifier"),
+                String::from("in"),
+                String::from("number literal"),
+                String::from("range"),
+                String::from("identifier"),
+                String::from("end of file")
+            ]
+        );
+
+        assert_eq!(
+            token_lexemes,
+            vec![
+                String::from("'outer"),
+                String::from(":"),
+                String::from("for"),
+                String::from("i"),
+                String::from("in"),
+                String::from("0"),
+                String::from(".."),
+                String::from("n"),
+                String::from("")
+            ]
+        )
+    }
+
+    #[test]
+    fn test_compound_assignment() {
+        use crate::lexer::Lexer;
+
+        let source = "x += 1 -= 2 *= 3 /= 4 ^= 5 x++ x--";
+        let mut lexer = Lexer::new(source);
+
+        let (token_names, _) = lexer.get_token_names_and_lexemes_vec();
+
+        assert_eq!(
+            token_names,
+            vec![
+                String::from("identifier"),
+                String::from("plus equal"),
+                String::from("number literal"),
+                String::from("minus equal"),
+                String::from("number literal"),
+                String::from("multiply equal"),
+                String::from("number literal"),
+                String::from("divide equal"),
+                String::from("number literal"),
+                String::from("power equal"),
+                String::from("number literal"),
+                String::from("identifier"),
+                String::from("increment"),
+                String::from("identifier"),
+                String::from("decrement"),
+                String::from("end of file")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_increment_position() {
+        use crate::lexer::Lexer;
+
+        let source = "a--b --x a - -b xs[0]++ f()-- i++\n";
+        let mut lexer = Lexer::new(source);
+
+        let (token_names, _) = lexer.get_token_names_and_lexemes_vec();
+
+        assert_eq!(
+            token_names,
+            vec![
+                String::from("identifier"),
+                String::from("minus"),
+                String::from("minus"),
+                String::from("identifier"),
+                String::from("minus"),
+                String::from("minus"),
+                String::from("identifier"),
+                String::from("identifier"),
+                String::from("minus"),
+                String::from("minus"),
+                String::from("identifier"),
+                String::from("identifier"),
+                String::from("left square bracket"),
+                String::from("number literal"),
+                String::from("right square bracket"),
+                String::from("increment"),
+                String::from("identifier"),
+                String::from("left parenthesis"),
+                String::from("right parenthesis"),
+                String::from("decrement"),
+                String::from("identifier"),
+                String::from("increment"),
+                String::from("end of file")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_null_safety_operators() {
+        use crate::lexer::Lexer;
+
+        let source = "i32? x ?? a?.b";
+        let mut lexer = Lexer::new(source);
+
+        let (token_names, _) = lexer.get_token_names_and_lexemes_vec();
+
+        assert_eq!(
+            token_names,
+            vec![
+                String::from("i32"),
+                String::from("question mark"),
+                String::from("identifier"),
+                String::from("null coalesce"),
+                String::from("identifier"),
+                String::from("optional dot"),
+                String::from("identifier"),
+                String::from("end of file")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_module_keywords() {
+        use crate::lexer::Lexer;
+
+        let source = "import m from \"m.vs\"\npub print";
+        let mut lexer = Lexer::new(source);
+
+        let (token_names, _) = lexer.get_token_names_and_lexemes_vec();
+
+        assert_eq!(
+            token_names,
+            vec![
+                String::from("import"),
+                String::from("identifier"),
+                String::from("from"),
+                String::from("string start"),
+                String::from("string literal"),
+                String::from("string end"),
+                String::from("pub"),
+                String::from("print"),
+                String::from("end of file")
+            ]
+        );
+    }
 }
@@ -0,0 +1,272 @@
+#[cfg(test)]
+mod test {
+    use crate::{ value::Value, vm::{ InterpretResult, VM } };
+
+    #[test]
+    fn test_list_literals() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret("[1, 2, 3] as string");
+        assert_eq!(result, InterpretResult::Debug(Value::String("[1, 2, 3]".to_string())));
+        vm.free_vm();
+
+        let result = vm.interpret("\"xs = \" + [1, \"two\", [3.5, null]]");
+        assert_eq!(
+            result,
+            InterpretResult::Debug(Value::String("xs = [1, \"two\", [3.5, null]]".to_string()))
+        );
+        vm.free_vm();
+
+        let result = vm.interpret("xs := [1, \"two\", [3.5, null], true,]\n\"{xs}\"");
+        assert_eq!(
+            result,
+            InterpretResult::Debug(Value::String("[1, \"two\", [3.5, null], true]".to_string()))
+        );
+        vm.free_vm();
+
+        let result = vm.interpret("[] as string");
+        assert_eq!(result, InterpretResult::Debug(Value::String("[]".to_string())));
+        vm.free_vm();
+
+        let result = vm.interpret("[1, 2");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_list_indexing() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret("xs := [10, 20, 30]\nxs[1]");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(20)));
+        vm.free_vm();
+
+        let result = vm.interpret("xs := [[1, 2], [3, 4]]\nxs[1][0]");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(3)));
+        vm.free_vm();
+
+        let result = vm.interpret("xs := [10, 20, 30]\nu8 i := 2\nxs[i]");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(30)));
+        vm.free_vm();
+
+        let result = vm.interpret("xs := [10, 20, 30]\nxs[xs.len() - 1]");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(30)));
+        vm.free_vm();
+
+        let result = vm.interpret("xs := [10, 20, 30]\nxs[3]");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret("xs := [10, 20, 30]\nxs[-1]");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret("xs := [10, 20, 30]\nxs[1.0]");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret("x := 5\nx[0]");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_list_assignment() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret("mut xs := [1, 2, 3]\nxs[0] = 5\nxs[0] + xs[2]");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(8)));
+        vm.free_vm();
+
+        let result = vm.interpret("mut xs := [1, 2, 3]\nxs[1] = 7");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(7)));
+        vm.free_vm();
+
+        let result = vm.interpret("mut xs := [[1], [2]]\nxs[1][0] = 9\nxs[1][0]");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(9)));
+        vm.free_vm();
+
+        let result = vm.interpret("mut xs := [1, 2, 3]\nxs[3] = 4");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret("xs := [1, 2, 3]\nxs[0] = 5");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("xs := [[1], [2]]\nxs[1][0] = 9");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret(
+            "fn f() {\n mut xs := [1, 2]\n xs[0] = 3\n return xs[0]\n}\nf()"
+        );
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(3)));
+        vm.free_vm();
+
+        let result = vm.interpret("fn f() {\n xs := [1, 2]\n xs[0] = 3\n}");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("mut xs := [1]\nmut ys := xs\nys[0] = 2\nxs[0]");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(2)));
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_list_methods() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret("xs := [1, 2, 3]\nxs.len()");
+        assert_eq!(result, InterpretResult::Debug(Value::Int64(3)));
+        vm.free_vm();
+
+        let result = vm.interpret("mut xs := [1]\nxs.push(2)\nxs.push(3)\nxs.len()");
+        assert_eq!(result, InterpretResult::Debug(Value::Int64(3)));
+        vm.free_vm();
+
+        let result = vm.interpret("mut xs := [1, 2]\nxs.pop()");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(2)));
+        vm.free_vm();
+
+        let result = vm.interpret("mut xs := [1, 2]\nxs.pop()\nxs.len()");
+        assert_eq!(result, InterpretResult::Debug(Value::Int64(1)));
+        vm.free_vm();
+
+        let result = vm.interpret("mut xs := []\nxs.pop()");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret("mut xs := []\nxs.push()");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret("xs := []\nxs.shuffle()");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret("xs := [1]\nxs.push(2)");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("fn mk() {\nreturn [1]\n}\nxs := mk()\nxs.push(5)");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("fn mk() {\nreturn [1]\n}\nxs := mk()\nxs[0] = 7");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("fn mk() {\nreturn [1]\n}\nmut xs := mk()\nxs.push(5)\nxs.len()");
+        assert_eq!(result, InterpretResult::Debug(Value::Int64(2)));
+        vm.free_vm();
+
+        let result = vm.interpret("xs := [1]\nmut ys := xs\nys.push(2)\nxs.len()");
+        assert_eq!(result, InterpretResult::Debug(Value::Int64(2)));
+        vm.free_vm();
+
+        let result = vm.interpret("fn add(mut ys) {\nys.push(2)\n}\nxs := [1]\nadd(xs)\nxs.len()");
+        assert_eq!(result, InterpretResult::Debug(Value::Int64(2)));
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_len() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret("len([1, 2, 3])");
+        assert_eq!(result, InterpretResult::Debug(Value::Int64(3)));
+        vm.free_vm();
+
+        let result = vm.interpret("mut xs := []\nxs.push(1)\nlen(xs)");
+        assert_eq!(result, InterpretResult::Debug(Value::Int64(1)));
+        vm.free_vm();
+
+        let result = vm.interpret("len({ \"a\": 1 }) + len((1, 2)) + len(\"abc\")");
+        assert_eq!(result, InterpretResult::Debug(Value::Int64(6)));
+        vm.free_vm();
+
+        let result = vm.interpret("len(1)");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret("len()");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_list_equality() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret("[1, 2, 3] == [1, 2, 3]");
+        assert_eq!(result, InterpretResult::Debug(Value::Bool(true)));
+        vm.free_vm();
+
+        let result = vm.interpret("i64 x := 2\n[1, [x]] == [1.0, [2]]");
+        assert_eq!(result, InterpretResult::Debug(Value::Bool(true)));
+        vm.free_vm();
+
+        let result = vm.interpret("[1, 2] != [1, 2, 3]");
+        assert_eq!(result, InterpretResult::Debug(Value::Bool(true)));
+        vm.free_vm();
+
+        let result = vm.interpret("[\"a\"] == [\"b\"]");
+        assert_eq!(result, InterpretResult::Debug(Value::Bool(false)));
+        vm.free_vm();
+
+        let result = vm.interpret("[1] < [2]");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_self_containing_lists() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret("mut xs := [1]\nxs.push(xs)\nxs as string");
+        assert_eq!(result, InterpretResult::Debug(Value::String("[1, [...]]".to_string())));
+        vm.free_vm();
+
+        let result = vm.interpret("mut xs := [1]\nys := [2, xs]\nxs.push(ys)\n\"{ys}\"");
+        assert_eq!(result, InterpretResult::Debug(Value::String("[2, [1, [...]]]".to_string())));
+        vm.free_vm();
+
+        let result = vm.interpret("mut m := {\"a\": 1}\nm[\"self\"] = m\nm as string");
+        assert_eq!(
+            result,
+            InterpretResult::Debug(Value::String("{\"a\": 1, \"self\": {...}}".to_string()))
+        );
+        vm.free_vm();
+
+        let result = vm.interpret("mut xs := []\nxs.push(xs)\nxs == xs");
+        assert_eq!(result, InterpretResult::Debug(Value::Bool(true)));
+        vm.free_vm();
+
+        let result = vm.interpret("mut xs := []\nmut ys := []\nxs.push(xs)\nys.push(ys)\nxs == ys");
+        assert_eq!(result, InterpretResult::Debug(Value::Bool(true)));
+        vm.free_vm();
+
+        let result = vm.interpret("mut xs := [1]\nmut ys := [2]\nxs.push(xs)\nys.push(ys)\nxs == ys");
+        assert_eq!(result, InterpretResult::Debug(Value::Bool(false)));
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_list_type_checking() {
+        let mut vm = VM::new();
+        vm.set_type_checking(true);
+
+        let result = vm.interpret("x := 5\nx[0]");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("xs := [1]\nxs[\"a\"]");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("xs := [1]\nxs[0]");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(1)));
+        vm.free_vm();
+    }
+}
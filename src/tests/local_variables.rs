@@ -0,0 +1,43 @@
+#[cfg(test)]
+mod test {
+    use crate::{ value::Value, vm::{ InterpretResult, VM } };
+
+    #[test]
+    fn test_block_scope() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret("mut r := 0\n{\n x := 5\n r = x * 2\n}\nr");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(10)));
+        vm.free_vm();
+
+        let result = vm.interpret("mut r := 0\n{\n x := 2\n {\n y := 3\n r = x + y\n }\n}\nr");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(5)));
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_local_shadowing() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret("x := 1\nmut r := 0\n{\n x := x + 1\n r = x\n}\nr + x");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(3)));
+        vm.free_vm();
+
+        let result = vm.interpret("mut r := 0\n{\n x := 1\n x := x * 10\n r = x\n}\nr");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(10)));
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_local_mutability() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret("mut r := 0\n{\n mut x := 1\n x = x + 4\n r = x\n}\nr");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(5)));
+        vm.free_vm();
+
+        let result = vm.interpret("{\n x := 1\n x = 2\n}");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+    }
+}
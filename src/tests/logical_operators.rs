@@ -0,0 +1,55 @@
+#[cfg(test)]
+mod test {
+    use crate::{ value::Value, vm::{ InterpretResult, VM } };
+
+    #[test]
+    fn test_and() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret("true and false");
+        assert_eq!(result, InterpretResult::Debug(Value::Bool(false)));
+        vm.free_vm();
+
+        let result = vm.interpret("1 and \"second\"");
+        assert_eq!(result, InterpretResult::Debug(Value::String("second".to_string())));
+        vm.free_vm();
+
+        let result = vm.interpret("null and 1");
+        assert_eq!(result, InterpretResult::Debug(Value::Null));
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_or() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret("false or true");
+        assert_eq!(result, InterpretResult::Debug(Value::Bool(true)));
+        vm.free_vm();
+
+        let result = vm.interpret("null or 2");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(2)));
+        vm.free_vm();
+
+        let result = vm.interpret("\"first\" or 2");
+        assert_eq!(result, InterpretResult::Debug(Value::String("first".to_string())));
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_short_circuit() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret("false and undefined_variable");
+        assert_eq!(result, InterpretResult::Debug(Value::Bool(false)));
+        vm.free_vm();
+
+        let result = vm.interpret("true or undefined_variable");
+        assert_eq!(result, InterpretResult::Debug(Value::Bool(true)));
+        vm.free_vm();
+
+        let result = vm.interpret("1 < 2 and 2 < 3 or false");
+        assert_eq!(result, InterpretResult::Debug(Value::Bool(true)));
+        vm.free_vm();
+    }
+}
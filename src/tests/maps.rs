@@ -0,0 +1,149 @@
+#[cfg(test)]
+mod test {
+    use crate::{ value::Value, vm::{ InterpretResult, VM } };
+
+    #[test]
+    fn test_map_literals() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret("({ \"a\": 1, 2: [true], false: null }) as string");
+        assert_eq!(
+            result,
+            InterpretResult::Debug(Value::String("{\"a\": 1, 2: [true], false: null}".to_string()))
+        );
+        vm.free_vm();
+
+        let result = vm.interpret("m := {}\n\"{m}\"");
+        assert_eq!(result, InterpretResult::Debug(Value::String("{}".to_string())));
+        vm.free_vm();
+
+        let result = vm.interpret("\"value: { {\"x\": 42}[\"x\"] }\"");
+        assert_eq!(result, InterpretResult::Debug(Value::String("value: 42".to_string())));
+        vm.free_vm();
+
+        let result = vm.interpret("mut x := 0\nif true { x = 1 }\nx");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(1)));
+        vm.free_vm();
+
+        let result = vm.interpret("({ 1.5: \"float\" })");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret("({ \"a\" 1 })");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_map_lookup_and_assignment() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret("m := { \"a\": 1, \"b\": 2 }\nm[\"b\"]");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(2)));
+        vm.free_vm();
+
+        let result = vm.interpret("m := { 1: \"one\" }\nu8 k := 1\nm[k]");
+        assert_eq!(result, InterpretResult::Debug(Value::String("one".to_string())));
+        vm.free_vm();
+
+        let result = vm.interpret("m := { \"a\": 1 }\nm[\"missing\"]");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret("mut m := { \"a\": 1 }\nm[\"a\"] = 10\nm[\"b\"] = 20\nm as string");
+        assert_eq!(
+            result,
+            InterpretResult::Debug(Value::String("{\"a\": 10, \"b\": 20}".to_string()))
+        );
+        vm.free_vm();
+
+        let result = vm.interpret("m := { \"a\": 1 }\nm[\"a\"] = 10");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_map_methods() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret("m := { \"a\": 1, \"b\": 2 }\nm.len()");
+        assert_eq!(result, InterpretResult::Debug(Value::Int64(2)));
+        vm.free_vm();
+
+        let result = vm.interpret("m := { \"a\": 1 }\nm.has(\"a\") and !m.has(\"b\")");
+        assert_eq!(result, InterpretResult::Debug(Value::Bool(true)));
+        vm.free_vm();
+
+        let result = vm.interpret("m := { \"a\": 1, \"b\": 2 }\nm.keys() as string + m.values()");
+        assert_eq!(result, InterpretResult::Debug(Value::String("[\"a\", \"b\"][1, 2]".to_string())));
+        vm.free_vm();
+
+        let result = vm.interpret(
+            "mut m := { \"a\": 1, \"b\": 2, \"c\": 3 }\nremoved := m.remove(\"b\")\nm[\"c\"] + removed"
+        );
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(5)));
+        vm.free_vm();
+
+        let result = vm.interpret("mut m := { \"a\": 1 }\nm.remove(\"b\")");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret("m := { \"a\": 1 }\nm.remove(\"a\")");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_map_equality() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret("({ \"a\": [1, 2] } == { \"a\": [1, 2] })");
+        assert_eq!(result, InterpretResult::Debug(Value::Bool(true)));
+        vm.free_vm();
+
+        let result = vm.interpret("({ \"a\": 1, \"b\": 2 } == { \"b\": 2, \"a\": 1 })");
+        assert_eq!(result, InterpretResult::Debug(Value::Bool(true)));
+        vm.free_vm();
+
+        let result = vm.interpret("({ \"a\": 1 } == { \"a\": 2 })");
+        assert_eq!(result, InterpretResult::Debug(Value::Bool(false)));
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_collection_iteration() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret(
+            "m := { \"a\": 1, \"b\": 2 }\nmut keys := \"\"\nfor k in m {\nkeys = keys + k\n}\nkeys"
+        );
+        assert_eq!(result, InterpretResult::Debug(Value::String("ab".to_string())));
+        vm.free_vm();
+
+        let result = vm.interpret("mut sum := 0\nfor x in [1, 2, 3] {\nsum = sum + x\n}\nsum");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(6)));
+        vm.free_vm();
+
+        let result = vm.interpret("mut count := 0\nfor x in [] {\ncount = count + 1\n}\ncount");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(0)));
+        vm.free_vm();
+
+        let result = vm.interpret("for x in 5 {\nprint x\n}");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_map_type_checking() {
+        let mut vm = VM::new();
+        vm.set_type_checking(true);
+
+        let result = vm.interpret("m := { \"a\": 1 }\nm[\"a\"]");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(1)));
+        vm.free_vm();
+
+        let result = vm.interpret("m := { 1: \"one\" }\nm[1.5]");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+    }
+}
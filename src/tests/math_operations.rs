@@ -92,11 +92,77 @@ mod test {
         let mut vm = VM::new();
 
         let result = vm.interpret("2 ^ 3");
-        assert_eq!(result, InterpretResult::Debug(Value::Float64(8.0)));
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(8)));
         vm.free_vm();
 
         let result = vm.interpret("2 ^ 4");
-        assert_eq!(result, InterpretResult::Debug(Value::Float64(16.0)));
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(16)));
+        vm.free_vm();
+
+        let result = vm.interpret("2 ^ -1");
+        assert_eq!(result, InterpretResult::Debug(Value::Float64(0.5)));
+        vm.free_vm();
+
+        let result = vm.interpret("2.0 ^ 3");
+        assert_eq!(result, InterpretResult::Debug(Value::Float64(8.0)));
+        vm.free_vm();
+
+        let result = vm.interpret("4000000000 ^ 2");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret("2 ^ 31");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_integer_division() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret("7 ~/ 2");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(3)));
+        vm.free_vm();
+
+        let result = vm.interpret("-7 ~/ 2");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(-3)));
+        vm.free_vm();
+
+        let result = vm.interpret("7.5 ~/ 2");
+        assert_eq!(result, InterpretResult::Debug(Value::Float64(3.0)));
+        vm.free_vm();
+
+        let result = vm.interpret("7 ~/ 0");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret("8 ~/ 3 // comment");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(2)));
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_modulo() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret("7 % 3");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(1)));
+        vm.free_vm();
+
+        let result = vm.interpret("-7 % 3");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(-1)));
+        vm.free_vm();
+
+        let result = vm.interpret("7.5 % 2");
+        assert_eq!(result, InterpretResult::Debug(Value::Float64(1.5)));
+        vm.free_vm();
+
+        let result = vm.interpret("1 + 7 % 3 * 2");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(3)));
+        vm.free_vm();
+
+        let result = vm.interpret("7 % 0");
+        assert_eq!(result, InterpretResult::RuntimeError);
         vm.free_vm();
     }
 
@@ -144,8 +210,87 @@ mod test {
         assert!(matches!(result, InterpretResult::CompileError));
         vm.free_vm();
 
-        // let result = vm.interpret("2 / 0");
-        // assert!(matches!(result, InterpretResult::RuntimeError)));
-        // vm.free_vm();
+        let result = vm.interpret("2 / 0");
+        assert!(matches!(result, InterpretResult::RuntimeError));
+        vm.free_vm();
+
+        let result = vm.interpret("2.0 / 0");
+        assert_eq!(result, InterpretResult::Debug(Value::Float64(f64::INFINITY)));
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_integer_overflow() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret("2147483647 + 1");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret("-2147483647 - 2");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret("65536 * 65536");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret("9223372036854775807 + 1");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret("x := -2147483647 - 1\n-x");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret("2147483647 + 3000000000");
+        assert_eq!(result, InterpretResult::Debug(Value::Int64(5147483647)));
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_wrapping_operators() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret("2147483647 +% 1");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(-2147483648)));
+        vm.free_vm();
+
+        let result = vm.interpret("-2147483647 -% 2");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(2147483647)));
+        vm.free_vm();
+
+        let result = vm.interpret("65536 *% 65536");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(0)));
+        vm.free_vm();
+
+        let result = vm.interpret("1.5 +% 1");
+        assert_eq!(result, InterpretResult::Debug(Value::Float64(2.5)));
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_saturating_operators() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret("2147483647 +| 1");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(2147483647)));
+        vm.free_vm();
+
+        let result = vm.interpret("-2147483647 -| 2");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(-2147483648)));
+        vm.free_vm();
+
+        let result = vm.interpret("9223372036854775807 *| 2");
+        assert_eq!(result, InterpretResult::Debug(Value::Int64(9223372036854775807)));
+        vm.free_vm();
+
+        let result = vm.interpret("2 *| 3 +| 1");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(7)));
+        vm.free_vm();
+
+        let result = vm.interpret("\"a\" +| 1");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
     }
 }
@@ -3,3 +3,23 @@ pub mod op_constants;
 pub mod lexer_tests;
 pub mod compares;
 pub mod string_interpolation;
+pub mod local_variables;
+pub mod control_flow;
+pub mod logical_operators;
+pub mod functions;
+pub mod closures;
+pub mod classes;
+pub mod typed_variables;
+pub mod type_checking;
+pub mod numeric_types;
+pub mod casts;
+pub mod lists;
+pub mod maps;
+pub mod tuples;
+pub mod compound_assignment;
+pub mod pattern_matching;
+pub mod enums;
+pub mod null_safety;
+pub mod string_methods;
+pub mod natives;
+pub mod modules;
@@ -0,0 +1,246 @@
+#[cfg(test)]
+mod test {
+    use std::{ fs, path::PathBuf };
+
+    use crate::{ value::Value, vm::{ InterpretResult, RuntimeError, VM } };
+
+    fn module_dir(name: &str, files: &[(&str, &str)]) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("viskum_modules_{}_{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+
+        for (file, source) in files {
+            let path = dir.join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, source).unwrap();
+        }
+        dir
+    }
+
+    #[test]
+    fn test_import_exports() {
+        let math =
+            "pub PI := 3.5
+            factor := 2
+            pub fn double(x) {
+                return x * factor
+            }
+            pub class Point {
+                fn init(x) {
+                    this.x = x
+                }
+            }
+            pub enum Dir { Up, Down }
+            ";
+        let dir = module_dir("exports", &[("math.vs", math)]);
+        let mut vm = VM::new();
+
+        let result = vm.interpret_file("import \"math.vs\"\ndouble(PI)", &dir.join("main.vs"));
+        assert_eq!(result, InterpretResult::Debug(Value::Float64(7.0)));
+        vm.free_vm();
+
+        let result = vm.interpret_file("import \"./math.vs\"\nPoint(4).x", &dir.join("main.vs"));
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(4)));
+        vm.free_vm();
+
+        let result = vm.interpret_file("import \"math.vs\"\nDir.Down as string", &dir.join("main.vs"));
+        assert_eq!(result, InterpretResult::Debug(Value::String("Dir.Down".to_string())));
+        vm.free_vm();
+
+        let result = vm.interpret_file("import \"math.vs\"\nfactor", &dir.join("main.vs"));
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret_file("import \"math.vs\"\ndouble = 1", &dir.join("main.vs"));
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_import_namespace() {
+        let math = "pub PI := 3.5\nfactor := 2\npub fn double(x) {\nreturn x * factor\n}";
+        let dir = module_dir("namespace", &[("lib/math.vs", math)]);
+        let mut vm = VM::new();
+
+        let result = vm.interpret_file(
+            "import math from \"lib/math.vs\"\nmath.double(math.PI)",
+            &dir.join("main.vs")
+        );
+        assert_eq!(result, InterpretResult::Debug(Value::Float64(7.0)));
+        vm.free_vm();
+
+        let result = vm.interpret_file("import math from \"lib/math.vs\"\n\"{math}\"", &dir.join("main.vs"));
+        assert_eq!(result, InterpretResult::Debug(Value::String("<module math>".to_string())));
+        vm.free_vm();
+
+        let result = vm.interpret_file(
+            "factor := 10\nimport m from \"lib/math.vs\"\nm.double(1) + factor",
+            &dir.join("main.vs")
+        );
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(12)));
+        vm.free_vm();
+
+        let result = vm.interpret_file("import math from \"lib/math.vs\"\nmath.factor", &dir.join("main.vs"));
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret_file("import math from \"lib/math.vs\"\nmath.PI = 1", &dir.join("main.vs"));
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret_file("import math from \"lib/math.vs\"\nmath = 1", &dir.join("main.vs"));
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_modules_run_once() {
+        let counter =
+            "mut count := 0
+            pub fn bump() {
+                count += 1
+                return count
+            }
+            ";
+        let user = "import counter from \"../counter.vs\"\npub fn bump_twice() {\ncounter.bump()\nreturn counter.bump()\n}";
+        let dir = module_dir("run_once", &[("counter.vs", counter), ("lib/user.vs", user)]);
+        let mut vm = VM::new();
+
+        let result = vm.interpret_file(
+            "import a from \"counter.vs\"\nimport \"lib/user.vs\"\na.bump()\nbump_twice()",
+            &dir.join("main.vs")
+        );
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(3)));
+        vm.free_vm();
+
+        let result = vm.interpret_file(
+            "import b from \"./lib/../counter.vs\"\nb.bump()",
+            &dir.join("main.vs")
+        );
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(4)));
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_module_exports_are_live() {
+        let counter = "pub mut count := 0\npub fn bump() {\ncount += 1\n}";
+        let dir = module_dir("live", &[("counter.vs", counter)]);
+        let mut vm = VM::new();
+
+        let result = vm.interpret_file(
+            "import counter from \"counter.vs\"\nbefore := counter.count\ncounter.bump()\ncounter.bump()\nbefore + counter.count",
+            &dir.join("main.vs")
+        );
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(2)));
+        vm.free_vm();
+
+        let result = vm.interpret_file(
+            "import c from \"counter.vs\"\nc.bump()\nc.count",
+            &dir.join("main.vs")
+        );
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(3)));
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_circular_imports() {
+        let dir = module_dir(
+            "circular",
+            &[
+                ("a.vs", "import \"b.vs\"\npub A := 1"),
+                ("b.vs", "import \"c.vs\"\npub B := 1"),
+                ("c.vs", "import \"a.vs\"\npub C := 1"),
+                ("self.vs", "import \"self.vs\""),
+            ]
+        );
+        let mut vm = VM::new();
+
+        let result = vm.interpret_file("import \"a.vs\"", &dir.join("main.vs"));
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret_file("import \"self.vs\"", &dir.join("main.vs"));
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret_file("import \"main.vs\"", &dir.join("main.vs"));
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_import_errors() {
+        let dir = module_dir("errors", &[("math.vs", "pub PI := 3.5"), ("broken.vs", "pub x := ")]);
+        let mut vm = VM::new();
+
+        let result = vm.interpret_file("import \"missing.vs\"", &dir.join("main.vs"));
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret_file("import \"broken.vs\"", &dir.join("main.vs"));
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret_file("import math \"math.vs\"", &dir.join("main.vs"));
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret_file("fn f() {\nimport \"math.vs\"\n}", &dir.join("main.vs"));
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret_file("fn f() {\npub x := 1\n}", &dir.join("main.vs"));
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret_file("pub print 1", &dir.join("main.vs"));
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_module_runtime_errors() {
+        let dir = module_dir("runtime_errors", &[("math.vs", "pub PI := 3.5"), ("bad.vs", "pub fn fail() {\nreturn -\"a\"\n}")]);
+        let bad = dir.join("bad.vs").canonicalize().unwrap();
+        let mut vm = VM::new();
+
+        let result = vm.interpret_file("import \"bad.vs\"\nfail()", &dir.join("main.vs"));
+        assert_eq!(result, InterpretResult::RuntimeError);
+        assert_eq!(
+            vm.get_last_error().cloned(),
+            Some(format!("[line 2 of {}]: Cannot negate \"String\"", bad.display()))
+        );
+        vm.free_vm();
+
+        let result = vm.interpret_file("import math from \"math.vs\"\nmath.PI = 4", &dir.join("main.vs"));
+        assert_eq!(result, InterpretResult::RuntimeError);
+        assert_eq!(
+            vm.get_last_error().map(String::as_str),
+            Some("[line 2]: Cannot set 'PI', members of module 'math' are read-only")
+        );
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_modules_with_natives_and_types() {
+        fn answer(_vm: &mut VM, _args: &[Value]) -> Result<Value, RuntimeError> {
+            Ok(Value::Int32(42))
+        }
+
+        let dir = module_dir("natives", &[("answer.vs", "pub i32 ANSWER := answer()\npub fn add(a, b) {\nreturn a + b\n}")]);
+        let mut vm = VM::new();
+        vm.set_type_checking(true);
+        vm.register_native("answer", 0, answer).unwrap();
+
+        let result = vm.interpret_file("import \"answer.vs\"\nadd(ANSWER, 1)", &dir.join("main.vs"));
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(43)));
+        vm.free_vm();
+
+        let result = vm.interpret_file("import \"answer.vs\"\nadd(1)", &dir.join("main.vs"));
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret_file("import \"answer.vs\"\n-\"a\" + ANSWER", &dir.join("main.vs"));
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+    }
+}
@@ -0,0 +1,146 @@
+#[cfg(test)]
+mod test {
+    use crate::{ value::Value, vm::{ InterpretResult, RuntimeError, VM } };
+
+    fn sqrt(_vm: &mut VM, args: &[Value]) -> Result<Value, RuntimeError> {
+        match args[0].as_float64() {
+            Some(number) if number >= 0.0 => Ok(Value::Float64(number.sqrt())),
+            Some(_) => Err(RuntimeError::new("Cannot take the square root of a negative number")),
+            None => Err(format!("Expected a number but got {}", args[0].to_type_string()).into()),
+        }
+    }
+
+    fn add(_vm: &mut VM, args: &[Value]) -> Result<Value, RuntimeError> {
+        Ok(Value::Float64(args[0].as_float64().unwrap_or(0.0) + args[1].as_float64().unwrap_or(0.0)))
+    }
+
+    #[test]
+    fn test_native_functions() {
+        let mut vm = VM::new();
+        vm.register_native("add", 2, add).unwrap();
+
+        let result = vm.interpret("add(1, 2.5)");
+        assert_eq!(result, InterpretResult::Debug(Value::Float64(3.5)));
+        vm.free_vm();
+
+        let result = vm.interpret("f := add\nf(1, 1)");
+        assert_eq!(result, InterpretResult::Debug(Value::Float64(2.0)));
+        vm.free_vm();
+
+        let result = vm.interpret("fn twice(g, x) {\nreturn g(x, x)\n}\ntwice(add, 4)");
+        assert_eq!(result, InterpretResult::Debug(Value::Float64(8.0)));
+        vm.free_vm();
+
+        let result = vm.interpret("1 + add(2, 3) * 2");
+        assert_eq!(result, InterpretResult::Debug(Value::Float64(11.0)));
+        vm.free_vm();
+
+        let result = vm.interpret("xs := [add(1, 1), add(2, 2)]\nxs[1]");
+        assert_eq!(result, InterpretResult::Debug(Value::Float64(4.0)));
+        vm.free_vm();
+
+        let result = vm.interpret("\"{add}\"");
+        assert_eq!(result, InterpretResult::Debug(Value::String("<native fn add>".to_string())));
+        vm.free_vm();
+
+        let result = vm.interpret("add(1)");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret("add = 1");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_native_name_clashes() {
+        let mut vm = VM::new();
+        vm.register_native("add", 2, add).unwrap();
+
+        assert!(vm.register_native("add", 1, sqrt).is_err());
+        assert!(vm.register_native("len", 1, sqrt).is_err());
+
+        let result = vm.interpret("mut x := 1");
+        assert_eq!(result, InterpretResult::Debug(Value::Null));
+        vm.free_vm();
+
+        assert!(vm.register_native("x", 1, sqrt).is_err());
+        assert!(vm.register_native("x.sqrt", 1, sqrt).is_err());
+
+        let result = vm.interpret("add(1, 2)");
+        assert_eq!(result, InterpretResult::Debug(Value::Float64(3.0)));
+        vm.free_vm();
+        let result = vm.interpret("x");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(1)));
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_native_modules() {
+        let mut vm = VM::new();
+        vm.register_native("math.sqrt", 1, sqrt).unwrap();
+        vm.register_native("math.add", 2, add).unwrap();
+
+        let result = vm.interpret("math.sqrt(16)");
+        assert_eq!(result, InterpretResult::Debug(Value::Float64(4.0)));
+        vm.free_vm();
+
+        let result = vm.interpret("math.add(math.sqrt(4), 1)");
+        assert_eq!(result, InterpretResult::Debug(Value::Float64(3.0)));
+        vm.free_vm();
+
+        let result = vm.interpret("math.cbrt(8)");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret("math = 1");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        assert!(vm.register_native("math.trig.sin", 1, sqrt).is_err());
+    }
+
+    #[test]
+    fn test_native_errors() {
+        let mut vm = VM::new();
+        vm.register_native("math.sqrt", 1, sqrt).unwrap();
+
+        let result = vm.interpret("x := 1\nmath.sqrt(-x)");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        assert_eq!(
+            vm.get_last_error().map(String::as_str),
+            Some("[line 2]: Cannot take the square root of a negative number")
+        );
+        vm.free_vm();
+
+        let result = vm.interpret("fn f() {\nreturn math.sqrt(\"a\")\n}\nf()");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        assert_eq!(vm.get_last_error().map(String::as_str), Some("[line 2]: Expected a number but got String"));
+        vm.free_vm();
+
+        let result = vm.interpret("math.sqrt(9)");
+        assert_eq!(result, InterpretResult::Debug(Value::Float64(3.0)));
+        vm.free_vm();
+        assert_eq!(vm.get_last_error(), None);
+    }
+
+    #[test]
+    fn test_native_type_checking() {
+        let mut vm = VM::new();
+        vm.set_type_checking(true);
+        vm.register_native("add", 2, add).unwrap();
+        vm.register_native("math.sqrt", 1, sqrt).unwrap();
+
+        let result = vm.interpret("add(1)");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("add(1, 2)");
+        assert_eq!(result, InterpretResult::Debug(Value::Float64(3.0)));
+        vm.free_vm();
+
+        let result = vm.interpret("math.sqrt(4)");
+        assert_eq!(result, InterpretResult::Debug(Value::Float64(2.0)));
+        vm.free_vm();
+    }
+}
@@ -0,0 +1,168 @@
+#[cfg(test)]
+mod test {
+    use crate::{ value::Value, vm::{ InterpretResult, VM } };
+
+    #[test]
+    fn test_nullable_variables() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret("i32? x := null\nx");
+        assert_eq!(result, InterpretResult::Debug(Value::Null));
+        vm.free_vm();
+
+        let result = vm.interpret("i64? x := 5\nx");
+        assert_eq!(result, InterpretResult::Debug(Value::Int64(5)));
+        vm.free_vm();
+
+        let result = vm.interpret("mut i32? x := 3\nx = null\nx");
+        assert_eq!(result, InterpretResult::Debug(Value::Null));
+        vm.free_vm();
+
+        let result = vm.interpret("fn f() {\nmut i64? x := null\nx = 8\nreturn x\n}\nf()");
+        assert_eq!(result, InterpretResult::Debug(Value::Int64(8)));
+        vm.free_vm();
+
+        let result = vm.interpret("fn f(i32? a) {\nreturn a\n}\nf(null)");
+        assert_eq!(result, InterpretResult::Debug(Value::Null));
+        vm.free_vm();
+
+        let result = vm.interpret("enum S { A, B }\nS? s := null\ns");
+        assert_eq!(result, InterpretResult::Debug(Value::Null));
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_null_coalescing() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret("i32? x := null\nx ?? 5");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(5)));
+        vm.free_vm();
+
+        let result = vm.interpret("i32? x := 3\nx ?? 5");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(3)));
+        vm.free_vm();
+
+        let result = vm.interpret("null ?? null ?? 9");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(9)));
+        vm.free_vm();
+
+        let result = vm.interpret("false ?? true");
+        assert_eq!(result, InterpretResult::Debug(Value::Bool(false)));
+        vm.free_vm();
+
+        let result = vm.interpret("fn f(i32? a) {\nreturn a ?? 100\n}\nf(null) + f(2)");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(102)));
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_optional_chaining() {
+        let mut vm = VM::new();
+        let point =
+            "class Point {
+                fn init(x) {
+                    this.x = x
+                }
+                fn get() {
+                    return this.x
+                }
+            }
+            ";
+
+        let result = vm.interpret(&format!("{}p := Point(4)\np?.x", point));
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(4)));
+        vm.free_vm();
+
+        let result = vm.interpret(&format!("{}p := Point(4)\np?.get()", point));
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(4)));
+        vm.free_vm();
+
+        let result = vm.interpret("p := null\np?.x");
+        assert_eq!(result, InterpretResult::Debug(Value::Null));
+        vm.free_vm();
+
+        let result = vm.interpret("p := null\np?.get()");
+        assert_eq!(result, InterpretResult::Debug(Value::Null));
+        vm.free_vm();
+
+        let result = vm.interpret("p := null\np?.x.y.z");
+        assert_eq!(result, InterpretResult::Debug(Value::Null));
+        vm.free_vm();
+
+        let result = vm.interpret(&format!("{}p := Point(null)\np.x?.y ?? 1", point));
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(1)));
+        vm.free_vm();
+
+        let result = vm.interpret("p := null\np?.x = 3");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret(&format!("{}p := Point(4)\np?.y", point));
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_null_safety_errors() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret("i32 x := null");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("i32? y := null\ni32 x := y");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("mut i32 x := 1\nx = null");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("fn f() {\ni32? y := 1\ni32 x := y\n}");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("i32? y := 3\nmut i32 x := 0\nx = y");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("enum S { A }\nS s := null");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("i32? y := null\ni32 x := y ?? 0\nx");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(0)));
+        vm.free_vm();
+
+        let result = vm.interpret("i32? y := null\ni32? z := null\ni32 x := y ?? z");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_nullable_type_checking() {
+        let mut vm = VM::new();
+        vm.set_type_checking(true);
+
+        let result = vm.interpret("i32? y := 2\ny + 1");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("i32? y := 2\n-y");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("string? s := \"a\"\n\"b\" + s");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("i32? y := 2\n(y ?? 0) + 1");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(3)));
+        vm.free_vm();
+
+        let result = vm.interpret("i32? y := 2\n\"{y}\"");
+        assert_eq!(result, InterpretResult::Debug(Value::String("2".to_string())));
+        vm.free_vm();
+    }
+}
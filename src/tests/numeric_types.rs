@@ -0,0 +1,191 @@
+#[cfg(test)]
+mod test {
+    use crate::{ value::Value, vm::{ InterpretResult, VM } };
+
+    #[test]
+    fn test_sized_declarations() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret("i8 x := -5\nx");
+        assert_eq!(result, InterpretResult::Debug(Value::Int8(-5)));
+        vm.free_vm();
+
+        let result = vm.interpret("i16 x := 300\nx");
+        assert_eq!(result, InterpretResult::Debug(Value::Int16(300)));
+        vm.free_vm();
+
+        let result = vm.interpret("int x := 7\nx");
+        assert_eq!(result, InterpretResult::Debug(Value::Int(7)));
+        vm.free_vm();
+
+        let result = vm.interpret("f32 x := 1.5\nx");
+        assert_eq!(result, InterpretResult::Debug(Value::Float32(1.5)));
+        vm.free_vm();
+
+        let result = vm.interpret("u8 x := 255\nx");
+        assert_eq!(result, InterpretResult::Debug(Value::UnsignedInt8(255)));
+        vm.free_vm();
+
+        let result = vm.interpret("u16 x := 65535\nx");
+        assert_eq!(result, InterpretResult::Debug(Value::UnsignedInt16(65535)));
+        vm.free_vm();
+
+        let result = vm.interpret("u32 x := 4000000000\nx");
+        assert_eq!(result, InterpretResult::Debug(Value::UnsignedInt32(4000000000)));
+        vm.free_vm();
+
+        let result = vm.interpret("u64 x := 5\nx");
+        assert_eq!(result, InterpretResult::Debug(Value::UnsignedInt64(5)));
+        vm.free_vm();
+
+        let result = vm.interpret("uint x := 5\nx");
+        assert_eq!(result, InterpretResult::Debug(Value::UnsignedInt(5)));
+        vm.free_vm();
+
+        let result = vm.interpret("fn f() {\n i8 x := 3\n return x\n}\nf()");
+        assert_eq!(result, InterpretResult::Debug(Value::Int8(3)));
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_out_of_range_declarations() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret("i8 x := 128");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("u8 x := -1");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret("i16 x := 1.5");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("y := 300\nu8 x := y");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret("mut u8 x := 1\nx = 256");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("mut u8 x := 1\ny := 256\nx = y");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_sized_arithmetic() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret("i8 x := 100\nx + x");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret("i8 x := 100\nx +% x");
+        assert_eq!(result, InterpretResult::Debug(Value::Int8(-56)));
+        vm.free_vm();
+
+        let result = vm.interpret("u8 x := 200\nx +| x");
+        assert_eq!(result, InterpretResult::Debug(Value::UnsignedInt8(255)));
+        vm.free_vm();
+
+        let result = vm.interpret("u8 x := 1\nu8 y := 2\nx -| y");
+        assert_eq!(result, InterpretResult::Debug(Value::UnsignedInt8(0)));
+        vm.free_vm();
+
+        let result = vm.interpret("u8 x := 5\ny := -x");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret("u16 x := 7\nu16 y := 2\nx ~/ y");
+        assert_eq!(result, InterpretResult::Debug(Value::UnsignedInt16(3)));
+        vm.free_vm();
+
+        let result = vm.interpret("f32 x := 1.5\nx * x");
+        assert_eq!(result, InterpretResult::Debug(Value::Float32(2.25)));
+        vm.free_vm();
+
+        let result = vm.interpret("f32 x := 3\nf32 y := 2\nx / y");
+        assert_eq!(result, InterpretResult::Debug(Value::Float32(1.5)));
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_numeric_promotion() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret("i8 x := 1\ni16 y := 2\nx + y");
+        assert_eq!(result, InterpretResult::Debug(Value::Int16(3)));
+        vm.free_vm();
+
+        let result = vm.interpret("u8 x := 1\nu32 y := 2\nx + y");
+        assert_eq!(result, InterpretResult::Debug(Value::UnsignedInt32(3)));
+        vm.free_vm();
+
+        let result = vm.interpret("i8 x := -1\nu8 y := 200\nx + y");
+        assert_eq!(result, InterpretResult::Debug(Value::Int16(199)));
+        vm.free_vm();
+
+        let result = vm.interpret("u8 x := 1\nx - 2");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(-1)));
+        vm.free_vm();
+
+        let result = vm.interpret("u64 x := 1\nx + 1");
+        assert_eq!(result, InterpretResult::Debug(Value::UnsignedInt64(2)));
+        vm.free_vm();
+
+        let result = vm.interpret("u64 x := 9223372036854775807\nu64 y := x + x\ni64 z := 1\ny - z");
+        assert_eq!(result, InterpretResult::Debug(Value::UnsignedInt64(18446744073709551613)));
+        vm.free_vm();
+
+        let result = vm.interpret("u64 x := 5\ni8 y := -1\nx + y");
+        assert_eq!(result, InterpretResult::Debug(Value::UnsignedInt64(4)));
+        vm.free_vm();
+
+        let result = vm.interpret("u64 x := 1\nx - 2");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret("f32 x := 1.5\nx + 1");
+        assert_eq!(result, InterpretResult::Debug(Value::Float32(2.5)));
+        vm.free_vm();
+
+        let result = vm.interpret("f32 x := 1.5\nx + 1.5");
+        assert_eq!(result, InterpretResult::Debug(Value::Float64(3.0)));
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_printing_sized_numbers() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret("u8 x := 7\n\"x = \" + x");
+        assert_eq!(result, InterpretResult::Debug(Value::String("x = 7".to_string())));
+        vm.free_vm();
+
+        let result = vm.interpret("f32 x := 0.5\n\"x = \" + x");
+        assert_eq!(result, InterpretResult::Debug(Value::String("x = 0.5".to_string())));
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_sized_type_checking() {
+        let mut vm = VM::new();
+        vm.set_type_checking(true);
+
+        let result = vm.interpret("f32 x := 1.5\ni16 y := x");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("i8 x := 1\nf32 y := x\ny");
+        assert_eq!(result, InterpretResult::Debug(Value::Float32(1.0)));
+        vm.free_vm();
+
+        let result = vm.interpret("u8 x := 1\n\"a\" - x");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+    }
+}
@@ -0,0 +1,193 @@
+#[cfg(test)]
+mod test {
+    use crate::{ value::Value, vm::{ InterpretResult, VM } };
+
+    #[test]
+    fn test_match_patterns() {
+        let mut vm = VM::new();
+        let describe =
+            "fn describe(v) {
+                return match v {
+                    1 => \"one\",
+                    \"a\" | \"b\" => \"letter\",
+                    -5 => \"minus five\",
+                    (x, y) if x > y => \"desc {x} {y}\",
+                    (x, y) => \"pair {x + y}\",
+                    [first, _, 3] => \"list {first}\",
+                    null => \"null\",
+                    n if n == 50 or n == 60 => \"big {n}\",
+                    _ => \"other\",
+                }
+            }
+            ";
+
+        let result = vm.interpret(&format!("{}describe(1)", describe));
+        assert_eq!(result, InterpretResult::Debug(Value::String("one".to_string())));
+        vm.free_vm();
+
+        let result = vm.interpret(&format!("{}describe(1.0)", describe));
+        assert_eq!(result, InterpretResult::Debug(Value::String("one".to_string())));
+        vm.free_vm();
+
+        let result = vm.interpret(&format!("{}describe(\"b\")", describe));
+        assert_eq!(result, InterpretResult::Debug(Value::String("letter".to_string())));
+        vm.free_vm();
+
+        let result = vm.interpret(&format!("{}describe(-5)", describe));
+        assert_eq!(result, InterpretResult::Debug(Value::String("minus five".to_string())));
+        vm.free_vm();
+
+        let result = vm.interpret(&format!("{}describe((5, 2))", describe));
+        assert_eq!(result, InterpretResult::Debug(Value::String("desc 5 2".to_string())));
+        vm.free_vm();
+
+        let result = vm.interpret(&format!("{}describe((1, 2))", describe));
+        assert_eq!(result, InterpretResult::Debug(Value::String("pair 3".to_string())));
+        vm.free_vm();
+
+        let result = vm.interpret(&format!("{}describe([9, 8, 3])", describe));
+        assert_eq!(result, InterpretResult::Debug(Value::String("list 9".to_string())));
+        vm.free_vm();
+
+        let result = vm.interpret(&format!("{}describe([9, 8, 4])", describe));
+        assert_eq!(result, InterpretResult::Debug(Value::String("other".to_string())));
+        vm.free_vm();
+
+        let result = vm.interpret(&format!("{}describe((1, 2, 3))", describe));
+        assert_eq!(result, InterpretResult::Debug(Value::String("other".to_string())));
+        vm.free_vm();
+
+        let result = vm.interpret(&format!("{}describe(null)", describe));
+        assert_eq!(result, InterpretResult::Debug(Value::String("null".to_string())));
+        vm.free_vm();
+
+        let result = vm.interpret(&format!("{}describe(50)", describe));
+        assert_eq!(result, InterpretResult::Debug(Value::String("big 50".to_string())));
+        vm.free_vm();
+
+        let result = vm.interpret(&format!("{}describe(7)", describe));
+        assert_eq!(result, InterpretResult::Debug(Value::String("other".to_string())));
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_match_positions() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret("x := match (1, (2, 3)) {\n(a, (b, c)) => a + b + c\n_ => 0\n}\nx");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(6)));
+        vm.free_vm();
+
+        let result = vm.interpret(
+            "{\na := 1\nb := match (a, 2) {\n(1, y) => match y { 2 => \"two\", _ => \"?\" }\n_ => \"no\"\n}\nprint b\n}\n\"done\""
+        );
+        assert_eq!(result, InterpretResult::Debug(Value::String("done".to_string())));
+        vm.free_vm();
+
+        let result = vm.interpret("f := match 5 { n => fn() { return n * 2 } }\nf()");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(10)));
+        vm.free_vm();
+
+        let result = vm.interpret("b := 3 > 2\nmatch b { true => \"yes\", false => \"no\" }");
+        assert_eq!(result, InterpretResult::Debug(Value::String("yes".to_string())));
+        vm.free_vm();
+
+        let result = vm.interpret("y := 1 + match 1 { n => n + 1 }\ny");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(3)));
+        vm.free_vm();
+
+        let result = vm.interpret(
+            "fn add(a, b, c) {\nreturn a + b + c\n}\nadd(1, match (2, 3) { (a, b) => a * b, _ => 0 }, match 1 { _ => 4 })"
+        );
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(11)));
+        vm.free_vm();
+
+        let result = vm.interpret(
+            "fn f() {\nx := 10\nreturn [x, match x { n if n > 5 => match n - 5 { m => m * x }, _ => 0 }]\n}\nf() as string"
+        );
+        assert_eq!(result, InterpretResult::Debug(Value::String("[10, 50]".to_string())));
+        vm.free_vm();
+
+        let result = vm.interpret(
+            "fn f() {\nmut m := { \"a\": match 1 { n => n }, \"b\": 2 }\nm[match 0 { _ => \"b\" }] += match 5 { n => n }\nreturn m[\"a\"] + m[\"b\"]\n}\nf()"
+        );
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(8)));
+        vm.free_vm();
+
+        let result = vm.interpret("\"a{[1][0] + match 2 { n => n }}b{match 4 { n => n }}\"");
+        assert_eq!(result, InterpretResult::Debug(Value::String("a3b4".to_string())));
+        vm.free_vm();
+
+        let result = vm.interpret(
+            "fn f(x) {\nreturn 1 + match x { n => fn() { return n * 2 } }()\n}\nf(4)"
+        );
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(9)));
+        vm.free_vm();
+
+        let result = vm.interpret("fn f() {\na := 1\nreturn (a, match 2 { n => { b := n + a } })\n}\nf() as string");
+        assert_eq!(result, InterpretResult::Debug(Value::String("(1, null)".to_string())));
+        vm.free_vm();
+
+        let result = vm.interpret(
+            "mut total := 0\nfor i in 0..4 {\ntotal = total + match i { 2 => { break }, n => n }\n}\ntotal"
+        );
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(1)));
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_match_block_arms() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret(
+            "mut total := 0\nfor i in 0..5 {\nmatch i {\n0 | 1 => { total += 100 }\nn => { total += n }\n}\n}\ntotal"
+        );
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(209)));
+        vm.free_vm();
+
+        let result = vm.interpret(
+            "fn first_even(xs) {\nfor x in xs {\nmatch x % 2 {\n0 => { return x }\n_ => { continue }\n}\n}\nreturn null\n}\nfirst_even([1, 3, 6, 7])"
+        );
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(6)));
+        vm.free_vm();
+
+        let result = vm.interpret(
+            "mut seen := 0\nfor x in [1, 2, 3] {\nmatch x {\n2 => { break }\n_ => { seen += x }\n}\n}\nseen"
+        );
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(1)));
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_match_errors() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret("match 1 { 1 => 2 }");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("match 1 { n if n > 1 => 1 }");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("mut b := true\nmatch b { true => 1, false => 0 }");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("match 1 { (a, 1) | (1, a) => a, _ => 0 }");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("match (1, 2) { (a, a) => a, _ => 0 }");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("match 1 { \"a{1}\" => 1, _ => 0 }");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("match 1 { 1 => 2 _ => 3 }");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+    }
+}
@@ -42,4 +42,17 @@ mod test {
         );
         vm.free_vm();
     }
+
+    #[test]
+    fn test_plain_strings() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret("\"\" + \"a\"");
+        assert_eq!(result, InterpretResult::Debug(Value::String("a".to_string())));
+        vm.free_vm();
+
+        let result = vm.interpret("\"a\" + 1 * 2");
+        assert_eq!(result, InterpretResult::Debug(Value::String("a2".to_string())));
+        vm.free_vm();
+    }
 }
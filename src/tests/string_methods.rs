@@ -0,0 +1,170 @@
+#[cfg(test)]
+mod test {
+    use crate::{ value::Value, vm::{ InterpretResult, RuntimeError, VM } };
+
+    #[test]
+    fn test_string_methods() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret("\"héllo\".len()");
+        assert_eq!(result, InterpretResult::Debug(Value::Int64(5)));
+        vm.free_vm();
+
+        let result = vm.interpret("s := \" Hi There \"\ns.trim().upper()");
+        assert_eq!(result, InterpretResult::Debug(Value::String("HI THERE".to_string())));
+        vm.free_vm();
+
+        let result = vm.interpret("\"ABC\".lower()");
+        assert_eq!(result, InterpretResult::Debug(Value::String("abc".to_string())));
+        vm.free_vm();
+
+        let result = vm.interpret("\"abc\".contains(\"bc\")");
+        assert_eq!(result, InterpretResult::Debug(Value::Bool(true)));
+        vm.free_vm();
+
+        let result = vm.interpret("\"abc\".starts_with(\"b\")");
+        assert_eq!(result, InterpretResult::Debug(Value::Bool(false)));
+        vm.free_vm();
+
+        let result = vm.interpret("\"a-b-c\".replace(\"-\", \"+\")");
+        assert_eq!(result, InterpretResult::Debug(Value::String("a+b+c".to_string())));
+        vm.free_vm();
+
+        let result = vm.interpret("\"héllo\".slice(1, 3)");
+        assert_eq!(result, InterpretResult::Debug(Value::String("él".to_string())));
+        vm.free_vm();
+
+        let result = vm.interpret("\"héllo\".slice(5, 5)");
+        assert_eq!(result, InterpretResult::Debug(Value::String("".to_string())));
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_string_lists() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret("\"a,b,,c\".split(\",\") as string");
+        assert_eq!(
+            result,
+            InterpretResult::Debug(Value::String("[\"a\", \"b\", \"\", \"c\"]".to_string()))
+        );
+        vm.free_vm();
+
+        let result = vm.interpret("\"hé\".chars() as string");
+        assert_eq!(result, InterpretResult::Debug(Value::String("[\"h\", \"é\"]".to_string())));
+        vm.free_vm();
+
+        let result = vm.interpret("\", \".join([1, \"x\", true])");
+        assert_eq!(result, InterpretResult::Debug(Value::String("1, x, true".to_string())));
+        vm.free_vm();
+
+        let result = vm.interpret("\"-\".join(\"a b c\".split(\" \"))");
+        assert_eq!(result, InterpretResult::Debug(Value::String("a-b-c".to_string())));
+        vm.free_vm();
+
+        let result = vm.interpret("\"\".join([])");
+        assert_eq!(result, InterpretResult::Debug(Value::String("".to_string())));
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_string_parsing() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret("\"42\".to_i32() + 1");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(43)));
+        vm.free_vm();
+
+        let result = vm.interpret("\" -7 \".to_i64()");
+        assert_eq!(result, InterpretResult::Debug(Value::Int64(-7)));
+        vm.free_vm();
+
+        let result = vm.interpret("\"2.5\".to_f64()");
+        assert_eq!(result, InterpretResult::Debug(Value::Float64(2.5)));
+        vm.free_vm();
+
+        let result = vm.interpret("\"9000000000\".to_i32()");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret("\"abc\".to_i32()");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret("\"\".to_f64()");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_string_method_errors() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret("\"ab\".slice(2, 1)");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret("\"ab\".slice(0, 3)");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret("\"ab\".slice(-1, 1)");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret("\"ab\".split(\"\")");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret("\"ab\".contains(1)");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret("\", \".join(\"ab\")");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret("\"ab\".len(1)");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret("\"ab\".missing()");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_strings_module() {
+        fn reverse(_vm: &mut VM, args: &[Value]) -> Result<Value, RuntimeError> {
+            match &args[0] {
+                Value::String(string) => Ok(Value::String(string.chars().rev().collect())),
+                _ => Err(RuntimeError::new("reverse expects a string")),
+            }
+        }
+
+        let mut vm = VM::new();
+
+        let result = vm.interpret("strings.upper(\"abc\")");
+        assert_eq!(result, InterpretResult::Debug(Value::String("ABC".to_string())));
+        vm.free_vm();
+
+        let result = vm.interpret("strings.split(\"a b\", \" \") as string");
+        assert_eq!(result, InterpretResult::Debug(Value::String("[\"a\", \"b\"]".to_string())));
+        vm.free_vm();
+
+        let result = vm.interpret("len(\"héllo\")");
+        assert_eq!(result, InterpretResult::Debug(Value::Int64(5)));
+        vm.free_vm();
+
+        let result = vm.interpret("strings.upper(1)");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        vm.register_native("strings.reverse", 1, reverse).unwrap();
+        assert!(vm.register_native("strings.empty", 0, reverse).is_err());
+
+        let result = vm.interpret("\"abc\".reverse()");
+        assert_eq!(result, InterpretResult::Debug(Value::String("cba".to_string())));
+        vm.free_vm();
+    }
+}
@@ -0,0 +1,128 @@
+#[cfg(test)]
+mod test {
+    use crate::{ value::Value, vm::{ InterpretResult, VM } };
+
+    #[test]
+    fn test_tuple_literals() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret("(1, \"x\", [true]) as string");
+        assert_eq!(result, InterpretResult::Debug(Value::String("(1, \"x\", [true])".to_string())));
+        vm.free_vm();
+
+        let result = vm.interpret("t := (5,)\n\"{t} {()} {(5)}\"");
+        assert_eq!(result, InterpretResult::Debug(Value::String("(5,) () 5".to_string())));
+        vm.free_vm();
+
+        let result = vm.interpret("t := (10, 20, 30)\nt[2] + t.len()");
+        assert_eq!(result, InterpretResult::Debug(Value::Int64(33)));
+        vm.free_vm();
+
+        let result = vm.interpret("(1, 2) == (1, 2.0) and (1, 2) != (2, 1)");
+        assert_eq!(result, InterpretResult::Debug(Value::Bool(true)));
+        vm.free_vm();
+
+        let result = vm.interpret("mut t := (1, 2)\nt[0] = 5");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret("t := (1, 2)\nt[2]");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_multiple_declarations() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret("a, b := 1, \"two\"\nb + a");
+        assert_eq!(result, InterpretResult::Debug(Value::String("two1".to_string())));
+        vm.free_vm();
+
+        let result = vm.interpret("fn pair() { return (3, 4) }\nx, y := pair()\nx * y");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(12)));
+        vm.free_vm();
+
+        let result = vm.interpret("mut total := 0\n{\nx, y, z := [1, 2, 3]\ntotal = x + y * z\n}\ntotal");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(7)));
+        vm.free_vm();
+
+        let result = vm.interpret("a, mut b := 1, 2\nb = 5\na + b");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(6)));
+        vm.free_vm();
+
+        let result = vm.interpret("{\na, mut b := 1, 2\na = 5\n}");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("a, b := 1, 2, 3");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("a, b := (1, 2, 3)");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret("a, b := 5");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_multiple_assignment() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret("mut a, mut b := 1, 2\na, b = b, a\n(a, b) as string");
+        assert_eq!(result, InterpretResult::Debug(Value::String("(2, 1)".to_string())));
+        vm.free_vm();
+
+        let result = vm.interpret(
+            "fn swap() {\nmut a := \"a\"\nmut b := \"b\"\na, b = b, a\nreturn a + b\n}\nswap()"
+        );
+        assert_eq!(result, InterpretResult::Debug(Value::String("ba".to_string())));
+        vm.free_vm();
+
+        let result = vm.interpret("mut /* swapped below */ a, mut b := 1, 2\na, b = b, a\na - b");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(1)));
+        vm.free_vm();
+
+        let result = vm.interpret("mut a, mut b := 0, 0\na, b = (1, 2)\na + b");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(3)));
+        vm.free_vm();
+
+        let result = vm.interpret("{\na, mut b := 1, 2\na, b = b, a\n}");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("a, mut b := 1, 2\na, b = b, a");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret("mut a, mut b := 1, 2\na, b = 1, 2, 3");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("fn f(a, b) { return a + b }\nx := 1\nf(x, 2)");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(3)));
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_multiple_assignment_types() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret("fn f() {\nmut i32 n := 1\nmut s := \"\"\nn, s = \"a\", \"b\"\n}\nf()");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        vm.set_type_checking(true);
+
+        let result = vm.interpret("mut i64 n := 1\nmut s := \"\"\nn, s = \"a\", \"b\"");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("t := (1, 2)\nt[\"a\"]");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+    }
+}
@@ -0,0 +1,119 @@
+#[cfg(test)]
+mod test {
+    use crate::{ value::Value, vm::{ InterpretResult, VM } };
+
+    #[test]
+    fn test_type_checking_is_optional() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret("\"a\" - 1");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        vm.set_type_checking(true);
+        let result = vm.interpret("\"a\" - 1");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_operand_mismatches() {
+        let mut vm = VM::new();
+        vm.set_type_checking(true);
+
+        let result = vm.interpret("x := \"a\"\ny := x * 2");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("-\"a\"");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("1 < \"a\"");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("x := 1 + 2.5\ny := x + true");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("x := 1 + 2.5\n\"x is \" + x");
+        assert_eq!(result, InterpretResult::Debug(Value::String("x is 3.5".to_string())));
+        vm.free_vm();
+
+        let result = vm.interpret("mut x := \"a\"\nx = 1\nx - 1");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(0)));
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_declared_type_mismatches() {
+        let mut vm = VM::new();
+        vm.set_type_checking(true);
+
+        let result = vm.interpret("x := \"a\"\ni32 y := x");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("mut i64 x := 1\nx = 1 + 2.5");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("{\n mut string s := \"a\"\n s = 1 + 2\n}");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("mut i64 x := 1\nx = x + 1\nx");
+        assert_eq!(result, InterpretResult::Debug(Value::Int64(2)));
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_unknown_variables() {
+        let mut vm = VM::new();
+        vm.set_type_checking(true);
+
+        let result = vm.interpret("x := y + 1");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("fn f() { return missing }");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("fn f() { return later }\nlater := 3\nf()");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(3)));
+        vm.free_vm();
+
+        vm.interpret("previous := 2");
+        let result = vm.interpret("previous * 2");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(4)));
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_call_arity() {
+        let mut vm = VM::new();
+        vm.set_type_checking(true);
+
+        let result = vm.interpret("fn add(a, b) { return a + b }\nadd(1)");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("{\n fn one(a) { return a }\n one(1, 2)\n}");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("f := fn(a) { return a }\nf()");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("x := 1\nx()");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("fn add(a, b) { return a + b }\nadd(1, 2)");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(3)));
+        vm.free_vm();
+    }
+}
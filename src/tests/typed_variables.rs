@@ -0,0 +1,155 @@
+#[cfg(test)]
+mod test {
+    use crate::{ value::{ Value, ValueType }, vm::{ InterpretResult, VM } };
+
+    #[test]
+    fn test_typed_global_declaration() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret("i32 x := 5\nx");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(5)));
+        vm.free_vm();
+
+        let result = vm.interpret("i64 x := 5\nx");
+        assert_eq!(result, InterpretResult::Debug(Value::Int64(5)));
+        vm.free_vm();
+
+        let result = vm.interpret("f64 x := 5\nx");
+        assert_eq!(result, InterpretResult::Debug(Value::Float64(5.0)));
+        vm.free_vm();
+
+        let result = vm.interpret("string x := \"hello\"\nx");
+        assert_eq!(result, InterpretResult::Debug(Value::String("hello".to_string())));
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_typed_declaration_compile_errors() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret("i32 x := 3000000000");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("i32 x := \"hello\"");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("string x := 5");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("i64 x := 2.5");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_typed_declaration_runtime_errors() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret("fn f() { return \"a\" }\ni32 x := f()");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret("fn f() { return 2.5 }\n{\n i32 x := f()\n}");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_typed_global_assignment() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret("mut i64 x := 1\nx = 2\nx");
+        assert_eq!(result, InterpretResult::Debug(Value::Int64(2)));
+        vm.free_vm();
+
+        let result = vm.interpret("mut i32 x := 1\nx = \"hello\"");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("mut i32 x := 1\nfn f() { x = \"hello\" }");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret("mut i32 x := 1\ny := \"hello\"\nx = y");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+
+        let result = vm.interpret("mut x := 1\nx = \"hello\"\nx");
+        assert_eq!(result, InterpretResult::Debug(Value::String("hello".to_string())));
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_typed_declarations_without_initializer() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret("i32 x\nx");
+        assert_eq!(result, InterpretResult::Debug(Value::Int32(0)));
+        vm.free_vm();
+
+        let result = vm.interpret("mut f64 x\nx = x + 1\nx");
+        assert_eq!(result, InterpretResult::Debug(Value::Float64(1.0)));
+        vm.free_vm();
+
+        let result = vm.interpret("string s\ns");
+        assert_eq!(result, InterpretResult::Debug(Value::String("".to_string())));
+        vm.free_vm();
+
+        let result = vm.interpret("i32? x\nx");
+        assert_eq!(result, InterpretResult::Debug(Value::Null));
+        vm.free_vm();
+
+        let result = vm.interpret("fn f() {\n mut u8 x\n x = 7\n return x\n}\nf()");
+        assert_eq!(result, InterpretResult::Debug(Value::UnsignedInt8(7)));
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_value_type_bytes() {
+        for byte in 0..=u8::MAX {
+            match ValueType::try_from(byte) {
+                Ok(value_type) => assert_eq!(u8::from(value_type), byte),
+                Err(_) => assert!(byte > u8::from(ValueType::Empty)),
+            }
+        }
+    }
+
+    #[test]
+    fn test_typed_locals() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret("mut r := 0\n{\n f64 x := 2\n r = x\n}\nr");
+        assert_eq!(result, InterpretResult::Debug(Value::Float64(2.0)));
+        vm.free_vm();
+
+        let result = vm.interpret("mut r := 0\n{\n mut i64 x := 1\n x = 3\n r = x\n}\nr");
+        assert_eq!(result, InterpretResult::Debug(Value::Int64(3)));
+        vm.free_vm();
+
+        let result = vm.interpret("{\n mut i32 x := 1\n x = \"hello\"\n}");
+        assert_eq!(result, InterpretResult::CompileError);
+        vm.free_vm();
+
+        let result = vm.interpret(
+            "fn outer() {\n mut i32 x := 1\n fn set(v) { x = v }\n set(true)\n}\nouter()"
+        );
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+    }
+
+    #[test]
+    fn test_typed_parameters() {
+        let mut vm = VM::new();
+
+        let result = vm.interpret("fn f(i64 a) { return a }\nf(2)");
+        assert_eq!(result, InterpretResult::Debug(Value::Int64(2)));
+        vm.free_vm();
+
+        let result = vm.interpret("fn f(i32 a) { return a }\nf(\"hello\")");
+        assert_eq!(result, InterpretResult::RuntimeError);
+        vm.free_vm();
+    }
+}
@@ -6,6 +6,8 @@ pub enum TokenType {
     TokenRightParen,
     TokenLeftBrace,
     TokenRightBrace,
+    TokenLeftBracket,
+    TokenRightBracket,
     TokenComma,
     TokenMinus,
     TokenDot,
@@ -14,6 +16,21 @@ pub enum TokenType {
     TokenSlash,
     TokenStar,
     TokenPower,
+    TokenTildeSlash,
+    TokenPercent,
+    TokenWrappingPlus,
+    TokenWrappingMinus,
+    TokenWrappingStar,
+    TokenSaturatingPlus,
+    TokenSaturatingMinus,
+    TokenSaturatingStar,
+    TokenPlusEqual,
+    TokenMinusEqual,
+    TokenStarEqual,
+    TokenSlashEqual,
+    TokenPowerEqual,
+    TokenPlusPlus,
+    TokenMinusMinus,
     TokenStringStart,
     TokenStringEnd,
     TokenInterpolationStart,
@@ -37,16 +54,29 @@ pub enum TokenType {
     TokenTypeInt16,
     TokenTypeInt32,
     TokenTypeInt64,
+    TokenTypeUnsignedInt,
+    TokenTypeUnsignedInt8,
+    TokenTypeUnsignedInt16,
+    TokenTypeUnsignedInt32,
+    TokenTypeUnsignedInt64,
     TokenDeclaration,
     TokenMutable,
     TokenColon,
+    TokenPipe,
+    TokenArrow,
+    TokenQuestion,
+    TokenQuestionQuestion,
+    TokenQuestionDot,
     TokenAnd,
+    TokenAs,
     TokenClass,
     TokenElse,
+    TokenEnum,
     TokenFalse,
     TokenFor,
     TokenFn,
     TokenIf,
+    TokenMatch,
     TokenNull,
     TokenOr,
     TokenPrint,
@@ -54,6 +84,15 @@ pub enum TokenType {
     TokenThis,
     TokenTrue,
     TokenWhile,
+    TokenIn,
+    TokenBreak,
+    TokenContinue,
+    TokenDotDot,
+    TokenLabel,
+    TokenSuper,
+    TokenImport,
+    TokenFrom,
+    TokenPub,
     TokenError(String),
     TokenEof,
 }
@@ -61,23 +100,62 @@ impl TokenType {
     pub fn parse_to_type(&self) -> ValueType {
         match self {
             TokenType::TokenTypeString => ValueType::String,
-            // TokenType::TokenTypeFloat32 => ValueType::Float32,
+            TokenType::TokenTypeFloat32 => ValueType::Float32,
             TokenType::TokenTypeFloat64 => ValueType::Float64,
-            // TokenType::TokenTypeInt => ValueType::Int,
-            // TokenType::TokenTypeInt8 => ValueType::Int8,
-            // TokenType::TokenTypeInt16 => ValueType::Int16,
+            TokenType::TokenTypeInt => ValueType::Int,
+            TokenType::TokenTypeInt8 => ValueType::Int8,
+            TokenType::TokenTypeInt16 => ValueType::Int16,
             TokenType::TokenTypeInt32 => ValueType::Int32,
             TokenType::TokenTypeInt64 => ValueType::Int64,
+            TokenType::TokenTypeUnsignedInt => ValueType::UnsignedInt,
+            TokenType::TokenTypeUnsignedInt8 => ValueType::UnsignedInt8,
+            TokenType::TokenTypeUnsignedInt16 => ValueType::UnsignedInt16,
+            TokenType::TokenTypeUnsignedInt32 => ValueType::UnsignedInt32,
+            TokenType::TokenTypeUnsignedInt64 => ValueType::UnsignedInt64,
             _ => ValueType::Empty,
         }
     }
 
+    pub fn is_type_keyword(&self) -> bool {
+        matches!(
+            self,
+            TokenType::TokenTypeString |
+                TokenType::TokenTypeFloat32 |
+                TokenType::TokenTypeFloat64 |
+                TokenType::TokenTypeInt |
+                TokenType::TokenTypeInt8 |
+                TokenType::TokenTypeInt16 |
+                TokenType::TokenTypeInt32 |
+                TokenType::TokenTypeInt64 |
+                TokenType::TokenTypeUnsignedInt |
+                TokenType::TokenTypeUnsignedInt8 |
+                TokenType::TokenTypeUnsignedInt16 |
+                TokenType::TokenTypeUnsignedInt32 |
+                TokenType::TokenTypeUnsignedInt64
+        )
+    }
+
+    pub fn is_compound_assignment(&self) -> bool {
+        matches!(
+            self,
+            TokenType::TokenPlusEqual |
+                TokenType::TokenMinusEqual |
+                TokenType::TokenStarEqual |
+                TokenType::TokenSlashEqual |
+                TokenType::TokenPowerEqual |
+                TokenType::TokenPlusPlus |
+                TokenType::TokenMinusMinus
+        )
+    }
+
     pub fn as_str(&self) -> &str {
         match self {
             TokenType::TokenLeftParen => "left parenthesis",
             TokenType::TokenRightParen => "right parenthesis",
             TokenType::TokenLeftBrace => "left curly brace",
             TokenType::TokenRightBrace => "right curly brace",
+            TokenType::TokenLeftBracket => "left square bracket",
+            TokenType::TokenRightBracket => "right square bracket",
             TokenType::TokenComma => "comma",
             TokenType::TokenMinus => "minus",
             TokenType::TokenDot => "dot",
@@ -86,6 +164,21 @@ impl TokenType {
             TokenType::TokenSlash => "divide",
             TokenType::TokenStar => "multiply",
             TokenType::TokenPower => "power",
+            TokenType::TokenTildeSlash => "integer divide",
+            TokenType::TokenPercent => "modulo",
+            TokenType::TokenWrappingPlus => "wrapping plus",
+            TokenType::TokenWrappingMinus => "wrapping minus",
+            TokenType::TokenWrappingStar => "wrapping multiply",
+            TokenType::TokenSaturatingPlus => "saturating plus",
+            TokenType::TokenSaturatingMinus => "saturating minus",
+            TokenType::TokenSaturatingStar => "saturating multiply",
+            TokenType::TokenPlusEqual => "plus equal",
+            TokenType::TokenMinusEqual => "minus equal",
+            TokenType::TokenStarEqual => "multiply equal",
+            TokenType::TokenSlashEqual => "divide equal",
+            TokenType::TokenPowerEqual => "power equal",
+            TokenType::TokenPlusPlus => "increment",
+            TokenType::TokenMinusMinus => "decrement",
             TokenType::TokenStringStart => "string start",
             TokenType::TokenStringEnd => "string end",
             TokenType::TokenInterpolationStart => "interpolation start",
@@ -109,16 +202,29 @@ impl TokenType {
             TokenType::TokenTypeInt16 => "i16",
             TokenType::TokenTypeInt32 => "i32",
             TokenType::TokenTypeInt64 => "i64",
+            TokenType::TokenTypeUnsignedInt => "uint",
+            TokenType::TokenTypeUnsignedInt8 => "u8",
+            TokenType::TokenTypeUnsignedInt16 => "u16",
+            TokenType::TokenTypeUnsignedInt32 => "u32",
+            TokenType::TokenTypeUnsignedInt64 => "u64",
             TokenType::TokenDeclaration => "declaration",
             TokenType::TokenMutable => "mut",
             TokenType::TokenColon => "colon",
+            TokenType::TokenPipe => "pipe",
+            TokenType::TokenArrow => "arrow",
+            TokenType::TokenQuestion => "question mark",
+            TokenType::TokenQuestionQuestion => "null coalesce",
+            TokenType::TokenQuestionDot => "optional dot",
             TokenType::TokenAnd => "and",
+            TokenType::TokenAs => "as",
             TokenType::TokenClass => "class",
             TokenType::TokenElse => "else",
+            TokenType::TokenEnum => "enum",
             TokenType::TokenFalse => "false",
             TokenType::TokenFor => "for",
             TokenType::TokenFn => "function",
             TokenType::TokenIf => "if",
+            TokenType::TokenMatch => "match",
             TokenType::TokenNull => "null",
             TokenType::TokenOr => "or",
             TokenType::TokenPrint => "print",
@@ -126,6 +232,15 @@ impl TokenType {
             TokenType::TokenThis => "this",
             TokenType::TokenTrue => "true",
             TokenType::TokenWhile => "while",
+            TokenType::TokenIn => "in",
+            TokenType::TokenBreak => "break",
+            TokenType::TokenContinue => "continue",
+            TokenType::TokenDotDot => "range",
+            TokenType::TokenLabel => "label",
+            TokenType::TokenSuper => "super",
+            TokenType::TokenImport => "import",
+            TokenType::TokenFrom => "from",
+            TokenType::TokenPub => "pub",
             TokenType::TokenError(String) => "error",
             TokenType::TokenEof => "end of file",
         }
@@ -1,28 +1,324 @@
-use std::ops::{ Add, Div, Mul, Neg, Sub };
+use std::{ cell::RefCell, cmp::Ordering, ops::{ Add, Div, Mul, Neg, Rem, Sub }, rc::Rc };
+
+use crate::{
+    object::{
+        BoundBuiltin,
+        BoundMethod,
+        Class,
+        Closure,
+        Enum,
+        EnumVariant,
+        Function,
+        Instance,
+        List,
+        Map,
+        Module,
+        NativeFunction,
+        Tuple,
+        VariantConstructor,
+    },
+};
 
 #[derive(Debug, PartialEq, Clone, PartialOrd)]
+#[repr(u8)]
 pub enum ValueType {
     Float64,
+    Float32,
     Int64,
     Int32,
+    Int16,
+    Int8,
+    Int,
+    UnsignedInt64,
+    UnsignedInt32,
+    UnsignedInt16,
+    UnsignedInt8,
+    UnsignedInt,
     String,
     Bool,
     Null,
+    Function,
+    Class,
+    Instance,
+    List,
+    Map,
+    Tuple,
+    Enum,
+    EnumType,
+    Module,
     Dynamic,
     Empty,
 }
 
-#[derive(Debug, PartialEq, Clone, PartialOrd)]
+impl ValueType {
+    pub fn to_type_string(&self) -> String {
+        match self {
+            ValueType::Float64 => "Float64".to_string(),
+            ValueType::Float32 => "Float32".to_string(),
+            ValueType::Int64 => "Int64".to_string(),
+            ValueType::Int32 => "Int32".to_string(),
+            ValueType::Int16 => "Int16".to_string(),
+            ValueType::Int8 => "Int8".to_string(),
+            ValueType::Int => "Int".to_string(),
+            ValueType::UnsignedInt64 => "UnsignedInt64".to_string(),
+            ValueType::UnsignedInt32 => "UnsignedInt32".to_string(),
+            ValueType::UnsignedInt16 => "UnsignedInt16".to_string(),
+            ValueType::UnsignedInt8 => "UnsignedInt8".to_string(),
+            ValueType::UnsignedInt => "UnsignedInt".to_string(),
+            ValueType::String => "String".to_string(),
+            ValueType::Bool => "Bool".to_string(),
+            ValueType::Null => "Null".to_string(),
+            ValueType::Function => "Function".to_string(),
+            ValueType::Class => "Class".to_string(),
+            ValueType::Instance => "Instance".to_string(),
+            ValueType::List => "List".to_string(),
+            ValueType::Map => "Map".to_string(),
+            ValueType::Tuple => "Tuple".to_string(),
+            ValueType::Enum => "Enum".to_string(),
+            ValueType::EnumType => "EnumType".to_string(),
+            ValueType::Module => "Module".to_string(),
+            ValueType::Dynamic => "Dynamic".to_string(),
+            ValueType::Empty => "Empty".to_string(),
+        }
+    }
+
+    pub fn is_dynamic(&self) -> bool {
+        matches!(self, ValueType::Dynamic | ValueType::Empty)
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.integer_bits().is_some()
+    }
+
+    pub fn is_float(&self) -> bool {
+        matches!(self, ValueType::Float64 | ValueType::Float32)
+    }
+
+    pub fn is_numeric(&self) -> bool {
+        self.is_integer() || self.is_float()
+    }
+
+    fn integer_bits(&self) -> Option<(u32, bool)> {
+        match self {
+            ValueType::Int64 => Some((64, true)),
+            ValueType::Int32 => Some((32, true)),
+            ValueType::Int16 => Some((16, true)),
+            ValueType::Int8 => Some((8, true)),
+            ValueType::Int => Some((isize::BITS, true)),
+            ValueType::UnsignedInt64 => Some((64, false)),
+            ValueType::UnsignedInt32 => Some((32, false)),
+            ValueType::UnsignedInt16 => Some((16, false)),
+            ValueType::UnsignedInt8 => Some((8, false)),
+            ValueType::UnsignedInt => Some((usize::BITS, false)),
+            _ => None,
+        }
+    }
+
+    fn sized_integer(bits: u32, signed: bool) -> ValueType {
+        match (bits, signed) {
+            (8, true) => ValueType::Int8,
+            (16, true) => ValueType::Int16,
+            (32, true) => ValueType::Int32,
+            (_, true) => ValueType::Int64,
+            (8, false) => ValueType::UnsignedInt8,
+            (16, false) => ValueType::UnsignedInt16,
+            (32, false) => ValueType::UnsignedInt32,
+            (_, false) => ValueType::UnsignedInt64,
+        }
+    }
+
+    pub fn promote(&self, other: &ValueType) -> Option<ValueType> {
+        if !(self.is_numeric() && other.is_numeric()) {
+            return None;
+        }
+
+        if self == other {
+            return Some(self.clone());
+        }
+
+        if self.is_float() || other.is_float() {
+            if self == &ValueType::Float64 || other == &ValueType::Float64 {
+                return Some(ValueType::Float64);
+            }
+            return Some(ValueType::Float32);
+        }
+
+        let (a_bits, a_signed) = self.integer_bits()?;
+        let (b_bits, b_signed) = other.integer_bits()?;
+
+        let bits = if a_signed == b_signed {
+            a_bits.max(b_bits)
+        } else {
+            let (signed_bits, unsigned_bits) = if a_signed {
+                (a_bits, b_bits)
+            } else {
+                (b_bits, a_bits)
+            };
+            if unsigned_bits >= 64 {
+                return Some(ValueType::UnsignedInt64);
+            }
+            signed_bits.max(unsigned_bits * 2)
+        };
+
+        Some(ValueType::sized_integer(bits, a_signed || b_signed))
+    }
+}
+
+impl ValueType {
+    const ALL: [ValueType; 26] = [
+        ValueType::Float64,
+        ValueType::Float32,
+        ValueType::Int64,
+        ValueType::Int32,
+        ValueType::Int16,
+        ValueType::Int8,
+        ValueType::Int,
+        ValueType::UnsignedInt64,
+        ValueType::UnsignedInt32,
+        ValueType::UnsignedInt16,
+        ValueType::UnsignedInt8,
+        ValueType::UnsignedInt,
+        ValueType::String,
+        ValueType::Bool,
+        ValueType::Null,
+        ValueType::Function,
+        ValueType::Class,
+        ValueType::Instance,
+        ValueType::List,
+        ValueType::Map,
+        ValueType::Tuple,
+        ValueType::Enum,
+        ValueType::EnumType,
+        ValueType::Module,
+        ValueType::Dynamic,
+        ValueType::Empty,
+    ];
+}
+
+impl TryFrom<u8> for ValueType {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        ValueType::ALL
+            .get(value as usize)
+            .cloned()
+            .ok_or_else(|| format!("Invalid value type {}", value))
+    }
+}
+
+impl From<ValueType> for u8 {
+    fn from(value: ValueType) -> Self {
+        value as u8
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StaticType {
+    value_type: ValueType,
+    arity: Option<usize>,
+    enum_name: Option<String>,
+    nullable: bool,
+}
+
+impl StaticType {
+    pub fn new(value_type: ValueType) -> Self {
+        Self { value_type, arity: None, enum_name: None, nullable: false }
+    }
+
+    pub fn function(arity: usize) -> Self {
+        Self { arity: Some(arity), ..Self::new(ValueType::Function) }
+    }
+
+    pub fn enum_variant(enum_name: String) -> Self {
+        Self { enum_name: Some(enum_name), ..Self::new(ValueType::Enum) }
+    }
+
+    pub fn nullable(self) -> Self {
+        Self { nullable: true, ..self }
+    }
+
+    pub fn non_nullable(self) -> Self {
+        Self { nullable: false, ..self }
+    }
+
+    pub fn dynamic() -> Self {
+        Self::new(ValueType::Dynamic)
+    }
+
+    pub fn get_value_type(&self) -> &ValueType {
+        &self.value_type
+    }
+
+    pub fn get_arity(&self) -> Option<usize> {
+        self.arity
+    }
+
+    pub fn get_enum_name(&self) -> Option<&String> {
+        self.enum_name.as_ref()
+    }
+
+    pub fn is_nullable(&self) -> bool {
+        self.nullable
+    }
+
+    pub fn is_dynamic(&self) -> bool {
+        self.value_type.is_dynamic()
+    }
+
+    pub fn may_be_null(&self) -> bool {
+        self.nullable || self.value_type == ValueType::Null
+    }
+
+    pub fn is_numeric(&self) -> bool {
+        self.value_type.is_numeric() && !self.nullable
+    }
+
+    pub fn to_type_string(&self) -> String {
+        let type_string = match &self.enum_name {
+            Some(enum_name) => enum_name.clone(),
+            None => self.value_type.to_type_string(),
+        };
+
+        if self.nullable {
+            format!("{}?", type_string)
+        } else {
+            type_string
+        }
+    }
+
+    pub fn is_printable(&self) -> bool {
+        self.is_numeric() ||
+            matches!(
+                self.value_type,
+                | ValueType::String
+                | ValueType::Bool
+                | ValueType::Null
+                | ValueType::List
+                | ValueType::Map
+                | ValueType::Tuple
+                | ValueType::Enum
+            )
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
 pub struct Variable {
     name: String,
     value: Value,
     value_type: ValueType,
+    nullable: bool,
     mutable: bool,
 }
 
 impl Variable {
-    pub fn new(name: String, value: Value, value_type: ValueType, mutable: bool) -> Self {
-        Self { name, value, value_type, mutable }
+    pub fn new(
+        name: String,
+        value: Value,
+        value_type: ValueType,
+        nullable: bool,
+        mutable: bool
+    ) -> Self {
+        Self { name, value, value_type, nullable, mutable }
     }
 
     pub fn get_name(&self) -> String {
@@ -37,17 +333,38 @@ impl Variable {
         self.value.clone()
     }
 
+    pub fn get_static_type(&self) -> StaticType {
+        match (&self.value, &self.value_type) {
+            (Value::EnumVariant(variant), ValueType::Enum) =>
+                StaticType::enum_variant(variant.get_enum().get_name().clone()),
+            (_, value_type) if !value_type.is_dynamic() && self.nullable => {
+                StaticType::new(value_type.clone()).nullable()
+            }
+            (_, value_type) if !value_type.is_dynamic() => StaticType::new(value_type.clone()),
+            (_, _) if self.mutable => StaticType::dynamic(),
+            (Value::Closure(closure), _) =>
+                StaticType::function(closure.get_function().get_arity()),
+            (Value::NativeFunction(native), _) => StaticType::function(native.get_arity()),
+            (value, _) => StaticType::new(value.to_value_type()),
+        }
+    }
+
     pub fn is_mutable(&self) -> bool {
         self.mutable
     }
 
-    pub fn set_value(&mut self, value: Value) -> bool {
-        if self.mutable {
-            self.value = value;
-            true
-        } else {
-            false
+    pub fn set_value(&mut self, value: Value) -> Result<Value, String> {
+        if !self.mutable {
+            return Err(format!("Cannot reassign immutable variable: {}", self.name));
         }
+
+        let value = if self.nullable {
+            value.coerce_to_nullable(&self.value_type)?
+        } else {
+            value.coerce_to(&self.value_type)?
+        };
+        self.value = value.clone();
+        Ok(value)
     }
 }
 
@@ -55,12 +372,13 @@ impl Variable {
 pub struct VariableDefinition {
     name: String,
     value_type: ValueType,
+    nullable: bool,
     mutable: bool,
 }
 
 impl VariableDefinition {
-    pub fn new(name: String, value_type: ValueType, mutable: bool) -> Self {
-        Self { name, value_type, mutable }
+    pub fn new(name: String, value_type: ValueType, nullable: bool, mutable: bool) -> Self {
+        Self { name, value_type, nullable, mutable }
     }
 
     pub fn get_name(&self) -> String {
@@ -71,41 +389,145 @@ impl VariableDefinition {
         self.value_type.clone()
     }
 
+    pub fn is_nullable(&self) -> bool {
+        self.nullable
+    }
+
     pub fn is_mutable(&self) -> bool {
         self.mutable
     }
 }
 
-#[derive(Debug, PartialEq, Clone, PartialOrd)]
+#[derive(Debug, PartialEq, Clone)]
 pub enum Value {
     Float64(f64),
-    // Float32(f32),
+    Float32(f32),
     Int64(i64),
     Int32(i32),
-    // Int16(i16),
-    // Int8(i8),
-    // Int(isize),
-    // UnsignedInt64(u64),
-    // UnsignedInt32(u32),
-    // UnsignedInt16(u16),
-    // UnsignedInt8(u8),
-    // UnsignedInt(usize),
+    Int16(i16),
+    Int8(i8),
+    Int(isize),
+    UnsignedInt64(u64),
+    UnsignedInt32(u32),
+    UnsignedInt16(u16),
+    UnsignedInt8(u8),
+    UnsignedInt(usize),
     String(String),
     Bool(bool),
     Null,
+    Function(Rc<Function>),
+    Closure(Rc<Closure>),
+    Class(Rc<RefCell<Class>>),
+    Instance(Rc<RefCell<Instance>>),
+    BoundMethod(Rc<BoundMethod>),
+    BoundBuiltin(Rc<BoundBuiltin>),
+    List(Rc<RefCell<List>>),
+    Map(Rc<RefCell<Map>>),
+    Tuple(Rc<Tuple>),
+    Enum(Rc<Enum>),
+    EnumVariant(Rc<EnumVariant>),
+    VariantConstructor(Rc<VariantConstructor>),
+    NativeFunction(Rc<NativeFunction>),
+    Module(Rc<RefCell<Module>>),
     VariableDefinition(VariableDefinition),
     VariableLookup(String),
 }
 
 impl Value {
     pub fn to_string(&self) -> String {
+        self.to_string_visiting(&mut Vec::new())
+    }
+
+    fn to_string_visiting(&self, visiting: &mut Vec<*const ()>) -> String {
         match self {
             Value::Float64(a) => a.to_string(),
+            Value::Float32(a) => a.to_string(),
             Value::Int64(a) => a.to_string(),
             Value::Int32(a) => a.to_string(),
+            Value::Int16(a) => a.to_string(),
+            Value::Int8(a) => a.to_string(),
+            Value::Int(a) => a.to_string(),
+            Value::UnsignedInt64(a) => a.to_string(),
+            Value::UnsignedInt32(a) => a.to_string(),
+            Value::UnsignedInt16(a) => a.to_string(),
+            Value::UnsignedInt8(a) => a.to_string(),
+            Value::UnsignedInt(a) => a.to_string(),
             Value::Bool(a) => a.to_string(),
             Value::Null => "null".to_string(),
             Value::String(a) => a.to_string(),
+            Value::Function(a) => format!("<fn {}>", a.get_name()),
+            Value::Closure(a) => format!("<fn {}>", a.get_function().get_name()),
+            Value::Class(a) => a.borrow().get_name().to_string(),
+            Value::Instance(a) => format!("{} instance", a.borrow().get_class().borrow().get_name()),
+            Value::BoundMethod(a) =>
+                format!("<fn {}>", a.get_method().get_function().get_name()),
+            Value::BoundBuiltin(a) => format!("<fn {}>", a.get_native().get_name()),
+            Value::List(a) => {
+                let pointer = Rc::as_ptr(a) as *const ();
+                if visiting.contains(&pointer) {
+                    return "[...]".to_string();
+                }
+
+                visiting.push(pointer);
+                let items = a
+                    .borrow()
+                    .get_items()
+                    .iter()
+                    .map(|item| item.to_repr_string_visiting(visiting))
+                    .collect::<Vec<String>>();
+                visiting.pop();
+                format!("[{}]", items.join(", "))
+            }
+            Value::Map(a) => {
+                let pointer = Rc::as_ptr(a) as *const ();
+                if visiting.contains(&pointer) {
+                    return "{...}".to_string();
+                }
+
+                visiting.push(pointer);
+                let entries = a
+                    .borrow()
+                    .get_entries()
+                    .iter()
+                    .map(|(key, value)| {
+                        format!(
+                            "{}: {}",
+                            key.to_repr_string_visiting(visiting),
+                            value.to_repr_string_visiting(visiting)
+                        )
+                    })
+                    .collect::<Vec<String>>();
+                visiting.pop();
+                format!("{{{}}}", entries.join(", "))
+            }
+            Value::Tuple(a) => {
+                let items = a
+                    .get_items()
+                    .iter()
+                    .map(|item| item.to_repr_string_visiting(visiting))
+                    .collect::<Vec<String>>();
+                match items.as_slice() {
+                    [item] => format!("({},)", item),
+                    _ => format!("({})", items.join(", ")),
+                }
+            }
+            Value::Enum(a) => a.get_name().to_string(),
+            Value::EnumVariant(a) => {
+                let name = format!("{}.{}", a.get_enum().get_name(), a.get_variant().get_name());
+                if a.get_payload().is_empty() {
+                    return name;
+                }
+
+                let items = a
+                    .get_payload()
+                    .iter()
+                    .map(|item| item.to_repr_string_visiting(visiting))
+                    .collect::<Vec<String>>();
+                format!("{}({})", name, items.join(", "))
+            }
+            Value::VariantConstructor(a) => format!("<fn {}>", a.get_name()),
+            Value::NativeFunction(a) => format!("<native fn {}>", a.get_name()),
+            Value::Module(a) => format!("<module {}>", a.borrow().get_name()),
             Value::VariableLookup(a) => "VariableLookup".to_string(),
             Value::VariableDefinition(a) => "VariableDefinition".to_string(),
         }
@@ -114,16 +536,58 @@ impl Value {
     pub fn to_type_string(&self) -> String {
         match self {
             Value::Float64(_) => "Float64".to_string(),
+            Value::Float32(_) => "Float32".to_string(),
             Value::Int64(_) => "Int64".to_string(),
             Value::Int32(_) => "Int32".to_string(),
+            Value::Int16(_) => "Int16".to_string(),
+            Value::Int8(_) => "Int8".to_string(),
+            Value::Int(_) => "Int".to_string(),
+            Value::UnsignedInt64(_) => "UnsignedInt64".to_string(),
+            Value::UnsignedInt32(_) => "UnsignedInt32".to_string(),
+            Value::UnsignedInt16(_) => "UnsignedInt16".to_string(),
+            Value::UnsignedInt8(_) => "UnsignedInt8".to_string(),
+            Value::UnsignedInt(_) => "UnsignedInt".to_string(),
             Value::Bool(_) => "Bool".to_string(),
             Value::Null => "Null".to_string(),
             Value::String(_) => "String".to_string(),
+            | Value::Function(_)
+            | Value::Closure(_)
+            | Value::BoundMethod(_)
+            | Value::BoundBuiltin(_)
+            | Value::VariantConstructor(_)
+            | Value::NativeFunction(_) => "Function".to_string(),
+            Value::Class(_) => "Class".to_string(),
+            Value::Enum(_) => "EnumType".to_string(),
+            Value::Instance(_) => "Instance".to_string(),
+            Value::List(_) => "List".to_string(),
+            Value::Map(_) => "Map".to_string(),
+            Value::Tuple(_) => "Tuple".to_string(),
+            Value::EnumVariant(a) => a.get_enum().get_name().to_string(),
+            Value::Module(_) => "Module".to_string(),
             Value::VariableLookup(a) => "VariableLookup".to_string(),
             Value::VariableDefinition(a) => "VariableDefinition".to_string(),
         }
     }
 
+    pub fn to_repr_string(&self) -> String {
+        self.to_repr_string_visiting(&mut Vec::new())
+    }
+
+    fn to_repr_string_visiting(&self, visiting: &mut Vec<*const ()>) -> String {
+        match self {
+            Value::String(a) => format!("{:?}", a),
+            _ => self.to_string_visiting(visiting),
+        }
+    }
+
+    pub fn new_list(items: Vec<Value>) -> Value {
+        Value::List(Rc::new(RefCell::new(List::new(items))))
+    }
+
+    pub fn new_tuple(items: Vec<Value>) -> Value {
+        Value::Tuple(Rc::new(Tuple::new(items)))
+    }
+
     pub fn is_falsey(&self) -> bool {
         matches!(self, Self::Null | Self::Bool(false))
     }
@@ -131,134 +595,570 @@ impl Value {
     pub fn to_value_type(&self) -> ValueType {
         match self {
             Value::Float64(_) => ValueType::Float64,
+            Value::Float32(_) => ValueType::Float32,
             Value::Int64(_) => ValueType::Int64,
             Value::Int32(_) => ValueType::Int32,
+            Value::Int16(_) => ValueType::Int16,
+            Value::Int8(_) => ValueType::Int8,
+            Value::Int(_) => ValueType::Int,
+            Value::UnsignedInt64(_) => ValueType::UnsignedInt64,
+            Value::UnsignedInt32(_) => ValueType::UnsignedInt32,
+            Value::UnsignedInt16(_) => ValueType::UnsignedInt16,
+            Value::UnsignedInt8(_) => ValueType::UnsignedInt8,
+            Value::UnsignedInt(_) => ValueType::UnsignedInt,
             Value::Bool(_) => ValueType::Bool,
             Value::Null => ValueType::Null,
             Value::String(_) => ValueType::String,
+            | Value::Function(_)
+            | Value::Closure(_)
+            | Value::BoundMethod(_)
+            | Value::BoundBuiltin(_)
+            | Value::VariantConstructor(_)
+            | Value::NativeFunction(_) => ValueType::Function,
+            Value::Class(_) => ValueType::Class,
+            Value::Enum(_) => ValueType::EnumType,
+            Value::Instance(_) => ValueType::Instance,
+            Value::List(_) => ValueType::List,
+            Value::Map(_) => ValueType::Map,
+            Value::Tuple(_) => ValueType::Tuple,
+            Value::EnumVariant(_) => ValueType::Enum,
+            Value::Module(_) => ValueType::Module,
             Value::VariableLookup(_) => ValueType::Dynamic,
             Value::VariableDefinition(_) => ValueType::Dynamic,
         }
     }
-}
 
-pub trait Pow: Sized {
-    fn pow(self, exp: Self) -> Result<Self, String>;
-}
+    pub fn default_for(value_type: &ValueType) -> Option<Value> {
+        match value_type {
+            ValueType::String => Some(Value::String(String::new())),
+            value_type if value_type.is_numeric() => Value::Int32(0).coerce_to(value_type).ok(),
+            _ => None,
+        }
+    }
 
-impl Neg for Value {
-    type Output = Result<Self, String>;
+    pub fn coerce_to(&self, value_type: &ValueType) -> Result<Value, String> {
+        if value_type.is_dynamic() || &self.to_value_type() == value_type {
+            return Ok(self.clone());
+        }
 
-    fn neg(self) -> Result<Self, String> {
+        match (self.as_integer(), self.as_float64()) {
+            (Some(a), _) if value_type.is_integer() =>
+                Value::from_integer(a, value_type).ok_or_else(||
+                    format!("Value {} does not fit in type {:?}", a, value_type.to_type_string())
+                ),
+            (_, Some(a)) if value_type.is_float() => Ok(Value::from_float(a, value_type)),
+            _ =>
+                Err(
+                    format!(
+                        "Expected type {:?} but got {:?}",
+                        value_type.to_type_string(),
+                        self.to_type_string()
+                    )
+                ),
+        }
+    }
+
+    pub fn coerce_to_nullable(&self, value_type: &ValueType) -> Result<Value, String> {
         match self {
-            Value::Float64(a) => Ok(Value::Float64(-a)),
-            Value::Int64(a) => Ok(Value::Int64(-a)),
-            Value::Int32(a) => Ok(Value::Int32(-a)),
-            _ => Err(format!("Cannot negate {:?}", self.to_type_string())),
+            Value::Null => Ok(Value::Null),
+            _ => self.coerce_to(value_type),
         }
     }
-}
 
-impl Add for Value {
-    type Output = Result<Self, String>;
+    pub fn cast_to(&self, value_type: &ValueType) -> Result<Value, String> {
+        let cannot_cast = || {
+            format!(
+                "Cannot cast {} of type {:?} to {:?} without losing information",
+                self.to_string(),
+                self.to_type_string(),
+                value_type.to_type_string()
+            )
+        };
 
-    fn add(self, other: Self) -> Result<Self, String> {
-        match (&self, &other) {
-            (Value::Float64(a), Value::Float64(b)) => Ok(Value::Float64(a + b)),
-            (Value::Int64(a), Value::Int64(b)) => Ok(Value::Int64(a + b)),
-            (Value::Int32(a), Value::Int32(b)) => Ok(Value::Int32(a + b)),
+        if value_type == &ValueType::String {
+            return if self.is_printable() {
+                Ok(Value::String(self.to_string()))
+            } else {
+                Err(cannot_cast())
+            };
+        }
+
+        if !value_type.is_numeric() {
+            return self.coerce_to(value_type);
+        }
+
+        let (integer, float) = match self {
+            Value::String(a) => {
+                let a = a.trim();
+                match a.parse::<i128>() {
+                    Ok(a) => (Some(a), Some(a as f64)),
+                    Err(_) => (None, a.parse::<f64>().ok()),
+                }
+            }
+            _ => (self.as_integer(), self.as_float64()),
+        };
+
+        match (integer, float) {
+            (Some(a), _) if value_type.is_integer() => {
+                Value::from_integer(a, value_type).ok_or_else(cannot_cast)
+            }
+            (Some(a), _) => Ok(Value::from_float(a as f64, value_type)),
+            (None, Some(a)) if value_type.is_integer() => {
+                if a.fract() != 0.0 || !a.is_finite() || a.abs() >= 2f64.powi(127) {
+                    return Err(cannot_cast());
+                }
+                Value::from_integer(a as i128, value_type).ok_or_else(cannot_cast)
+            }
+            (None, Some(a)) => {
+                let result = Value::from_float(a, value_type);
+                match result {
+                    Value::Float32(b) if b.is_infinite() && a.is_finite() => Err(cannot_cast()),
+                    _ => Ok(result),
+                }
+            }
+            _ => Err(cannot_cast()),
+        }
+    }
 
-            (Value::Int64(a), Value::Int32(b)) => Ok(Value::Int64(a + (*b as i64))),
-            (Value::Int32(a), Value::Int64(b)) => Ok(Value::Int64((*a as i64) + b)),
+    pub fn is_printable(&self) -> bool {
+        self.as_float64().is_some() ||
+            matches!(
+                self,
+                | Value::String(_)
+                | Value::Bool(_)
+                | Value::Null
+                | Value::List(_)
+                | Value::Map(_)
+                | Value::Tuple(_)
+                | Value::EnumVariant(_)
+            )
+    }
 
-            (Value::Float64(a), Value::Int64(b)) => Ok(Value::Float64(a + (*b as f64))),
-            (Value::Int64(a), Value::Float64(b)) => Ok(Value::Float64((*a as f64) + b)),
+    pub fn equals(&self, other: &Self) -> bool {
+        self.equals_visiting(other, &mut Vec::new())
+    }
 
-            (Value::Float64(a), Value::Int32(b)) => Ok(Value::Float64(a + (*b as f64))),
-            (Value::Int32(a), Value::Float64(b)) => Ok(Value::Float64((*a as f64) + b)),
+    fn equals_visiting(&self, other: &Self, visiting: &mut Vec<(*const (), *const ())>) -> bool {
+        if let (Value::List(a), Value::List(b)) = (self, other) {
+            let pair = (Rc::as_ptr(a) as *const (), Rc::as_ptr(b) as *const ());
+            if Rc::ptr_eq(a, b) || visiting.contains(&pair) {
+                return true;
+            }
 
-            (Value::String(a), Value::String(b)) => Ok(Value::String(format!("{}{}", a, b))),
+            visiting.push(pair);
+            let (a, b) = (a.borrow(), b.borrow());
+            let equal =
+                a.len() == b.len() &&
+                a
+                    .get_items()
+                    .iter()
+                    .zip(b.get_items())
+                    .all(|(a, b)| a.equals_visiting(b, visiting));
+            visiting.pop();
+            return equal;
+        }
 
-            (Value::String(a), Value::Int64(b)) => Ok(Value::String(format!("{}{}", a, b))),
-            (Value::String(a), Value::Int32(b)) => Ok(Value::String(format!("{}{}", a, b))),
-            (Value::String(a), Value::Float64(b)) => Ok(Value::String(format!("{}{}", a, b))),
-            (Value::String(a), Value::Bool(b)) => Ok(Value::String(format!("{}{}", a, b))),
-            (Value::String(a), Value::Null) => Ok(Value::String(format!("{}null", a))),
+        if let (Value::Tuple(a), Value::Tuple(b)) = (self, other) {
+            return a.len() == b.len() &&
+                a
+                    .get_items()
+                    .iter()
+                    .zip(b.get_items())
+                    .all(|(a, b)| a.equals_visiting(b, visiting));
+        }
 
-            (Value::Int64(a), Value::String(b)) => Ok(Value::String(format!("{}{}", a, b))),
-            (Value::Int32(a), Value::String(b)) => Ok(Value::String(format!("{}{}", a, b))),
-            (Value::Float64(a), Value::String(b)) => Ok(Value::String(format!("{}{}", a, b))),
-            (Value::Bool(a), Value::String(b)) => Ok(Value::String(format!("{}{}", a, b))),
-            (Value::Null, Value::String(b)) => Ok(Value::String(format!("null{}", b))),
+        if let (Value::EnumVariant(a), Value::EnumVariant(b)) = (self, other) {
+            return a.is_same_variant(b) &&
+                a
+                    .get_payload()
+                    .iter()
+                    .zip(b.get_payload())
+                    .all(|(a, b)| a.equals_visiting(b, visiting));
+        }
+
+        if let (Value::Map(a), Value::Map(b)) = (self, other) {
+            let pair = (Rc::as_ptr(a) as *const (), Rc::as_ptr(b) as *const ());
+            if Rc::ptr_eq(a, b) || visiting.contains(&pair) {
+                return true;
+            }
+
+            visiting.push(pair);
+            let (a, b) = (a.borrow(), b.borrow());
+            let equal =
+                a.len() == b.len() &&
+                a
+                    .get_entries()
+                    .iter()
+                    .all(|(key, value)| {
+                        b.get(key).is_ok_and(|other| value.equals_visiting(&other, visiting))
+                    });
+            visiting.pop();
+            return equal;
+        }
 
+        match self.numeric_cmp(other) {
+            Some(ordering) => ordering == Ordering::Equal,
+            None if self.as_float64().is_some() && other.as_float64().is_some() => false,
+            None => self == other,
+        }
+    }
+
+    pub fn compare(&self, other: &Self) -> Result<Option<Ordering>, String> {
+        if let (Value::String(a), Value::String(b)) = (self, other) {
+            return Ok(Some(a.cmp(b)));
+        }
+
+        if self.as_float64().is_some() && other.as_float64().is_some() {
+            return Ok(self.numeric_cmp(other));
+        }
+
+        Err(format!("Cannot compare {:?} and {:?}", self.to_type_string(), other.to_type_string()))
+    }
+
+    pub fn get_index(&self, index: &Value) -> Result<Value, String> {
+        match self {
+            Value::List(list) => list.borrow().get(index.as_index()?),
+            Value::Map(map) => map.borrow().get(index),
+            Value::Tuple(tuple) => tuple.get(index.as_index()?),
+            Value::EnumVariant(variant) => variant.get(index.as_index()?),
             _ =>
                 Err(
                     format!(
-                        "Cannot add {:?} and {:?}",
-                        self.to_type_string(),
-                        other.to_type_string()
+                        "Can only index lists, maps, tuples and enum variants, but got {:?}",
+                        self.to_type_string()
                     )
                 ),
         }
     }
-}
 
-impl Sub for Value {
-    type Output = Result<Self, String>;
+    pub fn set_index(&self, index: &Value, value: Value) -> Result<(), String> {
+        match self {
+            Value::List(list) => list.borrow_mut().set(index.as_index()?, value),
+            Value::Map(map) => map.borrow_mut().insert(index.clone(), value),
+            Value::Tuple(_) => Err("Tuples cannot be modified".to_string()),
+            Value::EnumVariant(_) => Err("Enum variants cannot be modified".to_string()),
+            _ => Err(format!("Can only index lists and maps, but got {:?}", self.to_type_string())),
+        }
+    }
 
-    fn sub(self, other: Self) -> Result<Self, String> {
-        match (&self, &other) {
-            (Value::Float64(a), Value::Float64(b)) => Ok(Value::Float64(a - b)),
-            (Value::Int64(a), Value::Int64(b)) => Ok(Value::Int64(a - b)),
-            (Value::Int32(a), Value::Int32(b)) => Ok(Value::Int32(a - b)),
+    pub fn to_iteration_list(&self) -> Result<Value, String> {
+        match self {
+            Value::List(list) => Ok(Value::new_list(list.borrow().get_items().clone())),
+            Value::Map(map) => {
+                let keys = map
+                    .borrow()
+                    .get_entries()
+                    .iter()
+                    .map(|(key, _)| key.clone())
+                    .collect();
+                Ok(Value::new_list(keys))
+            }
+            _ => Err(format!("Can only iterate over lists and maps, but got {:?}", self.to_type_string())),
+        }
+    }
 
-            (Value::Int64(a), Value::Int32(b)) => Ok(Value::Int64(a - (*b as i64))),
-            (Value::Int32(a), Value::Int64(b)) => Ok(Value::Int64((*a as i64) - b)),
+    pub fn unpack(&self, count: usize) -> Result<Vec<Value>, String> {
+        let items = match self {
+            Value::Tuple(tuple) => tuple.get_items().clone(),
+            Value::List(list) => list.borrow().get_items().clone(),
+            _ => {
+                return Err(
+                    format!("Can only unpack tuples and lists, but got {:?}", self.to_type_string())
+                );
+            }
+        };
 
-            (Value::Float64(a), Value::Int64(b)) => Ok(Value::Float64(a - (*b as f64))),
-            (Value::Int64(a), Value::Float64(b)) => Ok(Value::Float64((*a as f64) - b)),
+        if items.len() != count {
+            return Err(format!("Expected {} values to unpack but got {}", count, items.len()));
+        }
 
-            (Value::Float64(a), Value::Int32(b)) => Ok(Value::Float64(a - (*b as f64))),
-            (Value::Int32(a), Value::Float64(b)) => Ok(Value::Float64((*a as f64) - b)),
+        Ok(items)
+    }
 
-            _ =>
-                Err(
+    pub fn as_index(&self) -> Result<usize, String> {
+        match self.as_integer() {
+            Some(index) if index < 0 => Err(format!("Index cannot be negative, but got {}", index)),
+            Some(index) => usize::try_from(index).map_err(|_| format!("Index {} is too large", index)),
+            None => Err(format!("Index must be an integer, but got {:?}", self.to_type_string())),
+        }
+    }
+
+    fn numeric_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self.as_integer(), other.as_integer()) {
+            (Some(a), Some(b)) => Some(a.cmp(&b)),
+            (Some(a), None) => Value::integer_float_cmp(a, other.as_float64()?),
+            (None, Some(b)) => Value::integer_float_cmp(b, self.as_float64()?).map(Ordering::reverse),
+            (None, None) => self.as_float64()?.partial_cmp(&other.as_float64()?),
+        }
+    }
+
+    fn integer_float_cmp(a: i128, b: f64) -> Option<Ordering> {
+        if b.is_nan() {
+            return None;
+        }
+
+        let limit = 2f64.powi(127);
+        if b >= limit {
+            return Some(Ordering::Less);
+        }
+        if b < -limit {
+            return Some(Ordering::Greater);
+        }
+
+        let floor = b.floor();
+        match a.cmp(&(floor as i128)) {
+            Ordering::Equal if b > floor => Some(Ordering::Less),
+            ordering => Some(ordering),
+        }
+    }
+
+    pub fn as_integer(&self) -> Option<i128> {
+        match self {
+            Value::Int64(a) => Some(*a as i128),
+            Value::Int32(a) => Some(*a as i128),
+            Value::Int16(a) => Some(*a as i128),
+            Value::Int8(a) => Some(*a as i128),
+            Value::Int(a) => Some(*a as i128),
+            Value::UnsignedInt64(a) => Some(*a as i128),
+            Value::UnsignedInt32(a) => Some(*a as i128),
+            Value::UnsignedInt16(a) => Some(*a as i128),
+            Value::UnsignedInt8(a) => Some(*a as i128),
+            Value::UnsignedInt(a) => Some(*a as i128),
+            _ => None,
+        }
+    }
+
+    pub fn as_float64(&self) -> Option<f64> {
+        match self {
+            Value::Float64(a) => Some(*a),
+            Value::Float32(a) => Some(*a as f64),
+            _ => self.as_integer().map(|a| a as f64),
+        }
+    }
+
+    fn from_integer(value: i128, value_type: &ValueType) -> Option<Value> {
+        match value_type {
+            ValueType::Int64 => i64::try_from(value).ok().map(Value::Int64),
+            ValueType::Int32 => i32::try_from(value).ok().map(Value::Int32),
+            ValueType::Int16 => i16::try_from(value).ok().map(Value::Int16),
+            ValueType::Int8 => i8::try_from(value).ok().map(Value::Int8),
+            ValueType::Int => isize::try_from(value).ok().map(Value::Int),
+            ValueType::UnsignedInt64 => u64::try_from(value).ok().map(Value::UnsignedInt64),
+            ValueType::UnsignedInt32 => u32::try_from(value).ok().map(Value::UnsignedInt32),
+            ValueType::UnsignedInt16 => u16::try_from(value).ok().map(Value::UnsignedInt16),
+            ValueType::UnsignedInt8 => u8::try_from(value).ok().map(Value::UnsignedInt8),
+            ValueType::UnsignedInt => usize::try_from(value).ok().map(Value::UnsignedInt),
+            _ => None,
+        }
+    }
+
+    fn from_float(value: f64, value_type: &ValueType) -> Value {
+        match value_type {
+            ValueType::Float32 => Value::Float32(value as f32),
+            _ => Value::Float64(value),
+        }
+    }
+
+    fn wrap_integer(value: i128, value_type: &ValueType) -> Option<Value> {
+        let (bits, signed) = value_type.integer_bits()?;
+        let modulus = 1i128 << bits;
+
+        let mut wrapped = value.rem_euclid(modulus);
+        if signed && wrapped >= modulus / 2 {
+            wrapped -= modulus;
+        }
+
+        Value::from_integer(wrapped, value_type)
+    }
+
+    fn saturate_integer(value: i128, value_type: &ValueType) -> Option<Value> {
+        let (bits, signed) = value_type.integer_bits()?;
+        let (min, max) = if signed {
+            (-(1i128 << (bits - 1)), (1i128 << (bits - 1)) - 1)
+        } else {
+            (0, (1i128 << bits) - 1)
+        };
+
+        Value::from_integer(value.clamp(min, max), value_type)
+    }
+}
+
+pub trait Pow: Sized {
+    fn pow(self, exp: Self) -> Result<Self, String>;
+}
+
+pub trait IntegerDiv: Sized {
+    fn integer_div(self, other: Self) -> Result<Self, String>;
+}
+
+#[derive(Clone, Copy)]
+enum Overflow {
+    Checked,
+    Wrapping,
+    Saturating,
+}
+
+impl Value {
+    fn arithmetic(
+        &self,
+        other: &Self,
+        verb: &str,
+        overflow: Overflow,
+        integer: fn(i128, i128) -> Option<i128>,
+        float: fn(f64, f64) -> f64
+    ) -> Result<Self, String> {
+        let value_type = match self.to_value_type().promote(&other.to_value_type()) {
+            Some(value_type) => value_type,
+            None => {
+                return Err(
                     format!(
-                        "Cannot subtract {:?} and {:?}",
+                        "Cannot {} {:?} and {:?}",
+                        verb,
                         self.to_type_string(),
                         other.to_type_string()
                     )
+                );
+            }
+        };
+
+        if value_type.is_float() {
+            let result = float(self.as_float64().unwrap(), other.as_float64().unwrap());
+            return Ok(Value::from_float(result, &value_type));
+        }
+
+        let result = integer(self.as_integer().unwrap(), other.as_integer().unwrap());
+        let value = result.and_then(|result| {
+            match overflow {
+                Overflow::Checked => Value::from_integer(result, &value_type),
+                Overflow::Wrapping => Value::wrap_integer(result, &value_type),
+                Overflow::Saturating => Value::saturate_integer(result, &value_type),
+            }
+        });
+
+        value.ok_or_else(||
+            format!(
+                "Integer overflow when trying to {} {:?} and {:?}",
+                verb,
+                self.to_type_string(),
+                other.to_type_string()
+            )
+        )
+    }
+
+    fn check_integer_divisor(&self, other: &Self) -> Result<(), String> {
+        if self.as_integer().is_some() && other.as_integer() == Some(0) {
+            Err("Division by zero".to_string())
+        } else {
+            Ok(())
+        }
+    }
+
+    pub fn wrapping_add(self, other: Self) -> Result<Self, String> {
+        self.arithmetic(
+            &other,
+            "add",
+            Overflow::Wrapping,
+            |a, b| Some(a.wrapping_add(b)),
+            |a, b| a + b
+        )
+    }
+
+    pub fn wrapping_sub(self, other: Self) -> Result<Self, String> {
+        self.arithmetic(
+            &other,
+            "subtract",
+            Overflow::Wrapping,
+            |a, b| Some(a.wrapping_sub(b)),
+            |a, b| a - b
+        )
+    }
+
+    pub fn wrapping_mul(self, other: Self) -> Result<Self, String> {
+        self.arithmetic(
+            &other,
+            "multiply",
+            Overflow::Wrapping,
+            |a, b| Some(a.wrapping_mul(b)),
+            |a, b| a * b
+        )
+    }
+
+    pub fn saturating_add(self, other: Self) -> Result<Self, String> {
+        self.arithmetic(
+            &other,
+            "add",
+            Overflow::Saturating,
+            |a, b| Some(a.saturating_add(b)),
+            |a, b| a + b
+        )
+    }
+
+    pub fn saturating_sub(self, other: Self) -> Result<Self, String> {
+        self.arithmetic(
+            &other,
+            "subtract",
+            Overflow::Saturating,
+            |a, b| Some(a.saturating_sub(b)),
+            |a, b| a - b
+        )
+    }
+
+    pub fn saturating_mul(self, other: Self) -> Result<Self, String> {
+        self.arithmetic(
+            &other,
+            "multiply",
+            Overflow::Saturating,
+            |a, b| Some(a.saturating_mul(b)),
+            |a, b| a * b
+        )
+    }
+}
+
+impl Neg for Value {
+    type Output = Result<Self, String>;
+
+    fn neg(self) -> Result<Self, String> {
+        match (&self, self.as_integer()) {
+            (Value::Float64(a), _) => Ok(Value::Float64(-a)),
+            (Value::Float32(a), _) => Ok(Value::Float32(-a)),
+            (_, Some(a)) =>
+                Value::from_integer(-a, &self.to_value_type()).ok_or_else(||
+                    format!("Integer overflow when trying to negate {:?}", self.to_type_string())
                 ),
+            _ => Err(format!("Cannot negate {:?}", self.to_type_string())),
         }
     }
 }
 
-impl Mul for Value {
+impl Add for Value {
     type Output = Result<Self, String>;
 
-    fn mul(self, other: Self) -> Result<Self, String> {
+    fn add(self, other: Self) -> Result<Self, String> {
         match (&self, &other) {
-            (Value::Float64(a), Value::Float64(b)) => Ok(Value::Float64(a * b)),
-            (Value::Int64(a), Value::Int64(b)) => Ok(Value::Int64(a * b)),
-            (Value::Int32(a), Value::Int32(b)) => Ok(Value::Int32(a * b)),
+            (Value::String(a), b) if b.is_printable() => {
+                Ok(Value::String(format!("{}{}", a, b.to_string())))
+            }
+            (a, Value::String(b)) if a.is_printable() => {
+                Ok(Value::String(format!("{}{}", a.to_string(), b)))
+            }
+            _ => self.arithmetic(&other, "add", Overflow::Checked, i128::checked_add, |a, b| a + b),
+        }
+    }
+}
 
-            (Value::Int64(a), Value::Int32(b)) => Ok(Value::Int64(a * (*b as i64))),
-            (Value::Int32(a), Value::Int64(b)) => Ok(Value::Int64((*a as i64) * b)),
+impl Sub for Value {
+    type Output = Result<Self, String>;
 
-            (Value::Float64(a), Value::Int64(b)) => Ok(Value::Float64(a * (*b as f64))),
-            (Value::Int64(a), Value::Float64(b)) => Ok(Value::Float64((*a as f64) * b)),
+    fn sub(self, other: Self) -> Result<Self, String> {
+        self.arithmetic(&other, "subtract", Overflow::Checked, i128::checked_sub, |a, b| a - b)
+    }
+}
 
-            (Value::Float64(a), Value::Int32(b)) => Ok(Value::Float64(a * (*b as f64))),
-            (Value::Int32(a), Value::Float64(b)) => Ok(Value::Float64((*a as f64) * b)),
-            _ =>
-                Err(
-                    format!(
-                        "Cannot multiply {:?} and {:?}",
-                        self.to_type_string(),
-                        other.to_type_string()
-                    )
-                ),
-        }
+impl Mul for Value {
+    type Output = Result<Self, String>;
+
+    fn mul(self, other: Self) -> Result<Self, String> {
+        self.arithmetic(&other, "multiply", Overflow::Checked, i128::checked_mul, |a, b| a * b)
     }
 }
 
@@ -266,19 +1166,16 @@ impl Div for Value {
     type Output = Result<Self, String>;
 
     fn div(self, other: Self) -> Result<Self, String> {
-        match (&self, &other) {
-            (Value::Float64(a), Value::Float64(b)) => Ok(Value::Float64(a / b)),
-            (Value::Int64(a), Value::Int64(b)) => Ok(Value::Float64((*a as f64) / (*b as f64))),
-            (Value::Int32(a), Value::Int32(b)) => Ok(Value::Float64((*a as f64) / (*b as f64))),
-
-            (Value::Int64(a), Value::Int32(b)) => Ok(Value::Float64((*a as f64) / (*b as f64))),
-            (Value::Int32(a), Value::Int64(b)) => Ok(Value::Float64((*a as f64) / (*b as f64))),
+        self.check_integer_divisor(&other)?;
 
-            (Value::Float64(a), Value::Int64(b)) => Ok(Value::Float64(a / (*b as f64))),
-            (Value::Int64(a), Value::Float64(b)) => Ok(Value::Float64((*a as f64) / b)),
+        // Dividing integers gives a Float64, use `~/` to stay integral
+        let value_type = match self.to_value_type().promote(&other.to_value_type()) {
+            Some(value_type) if value_type.is_float() => value_type,
+            _ => ValueType::Float64,
+        };
 
-            (Value::Float64(a), Value::Int32(b)) => Ok(Value::Float64(a / (*b as f64))),
-            (Value::Int32(a), Value::Float64(b)) => Ok(Value::Float64((*a as f64) / b)),
+        match (self.as_float64(), other.as_float64()) {
+            (Some(a), Some(b)) => Ok(Value::from_float(a / b, &value_type)),
             _ =>
                 Err(
                     format!(
@@ -291,22 +1188,60 @@ impl Div for Value {
     }
 }
 
+impl IntegerDiv for Value {
+    fn integer_div(self, other: Self) -> Result<Self, String> {
+        self.check_integer_divisor(&other)?;
+        self.arithmetic(&other, "divide", Overflow::Checked, i128::checked_div, |a, b| {
+            (a / b).trunc()
+        })
+    }
+}
+
+impl Rem for Value {
+    type Output = Result<Self, String>;
+
+    fn rem(self, other: Self) -> Result<Self, String> {
+        self.check_integer_divisor(&other)?;
+        self.arithmetic(
+            &other,
+            "take the remainder of",
+            Overflow::Checked,
+            i128::checked_rem,
+            |a, b| a % b
+        )
+    }
+}
+
 impl Pow for Value {
     fn pow(self, exp: Self) -> Result<Self, String> {
-        match (&self, &exp) {
-            (Value::Float64(a), Value::Float64(b)) => Ok(Value::Float64(a.powf(*b))),
-            (Value::Int64(a), Value::Int64(b)) => Ok(Value::Float64((*a as f64).powf(*b as f64))),
-            (Value::Int32(a), Value::Int32(b)) => Ok(Value::Float64((*a as f64).powf(*b as f64))),
-
-            (Value::Int64(a), Value::Int32(b)) => Ok(Value::Float64((*a as f64).powf(*b as f64))),
-            (Value::Int32(a), Value::Int64(b)) => Ok(Value::Float64((*a as f64).powf(*b as f64))),
+        let is_negative = exp.as_integer().is_some_and(|b| b < 0);
 
-            (Value::Float64(a), Value::Int64(b)) => Ok(Value::Float64(a.powf(*b as f64))),
-            (Value::Int64(a), Value::Float64(b)) => Ok(Value::Float64((*a as f64).powf(*b))),
+        // Integers only stay integral when raised to a non-negative integer exponent
+        if self.as_integer().is_some() && exp.as_integer().is_some() && !is_negative {
+            return self
+                .arithmetic(
+                    &exp,
+                    "raise",
+                    Overflow::Checked,
+                    |a, b| u32::try_from(b).ok().and_then(|b| a.checked_pow(b)),
+                    f64::powf
+                )
+                .map_err(|_|
+                    format!(
+                        "Integer overflow when trying to raise {:?} to the power of {:?}",
+                        self.to_type_string(),
+                        exp.to_type_string()
+                    )
+                );
+        }
 
-            (Value::Float64(a), Value::Int32(b)) => Ok(Value::Float64(a.powf(*b as f64))),
-            (Value::Int32(a), Value::Float64(b)) => Ok(Value::Float64((*a as f64).powf(*b))),
+        let value_type = match self.to_value_type().promote(&exp.to_value_type()) {
+            Some(value_type) if value_type.is_float() => value_type,
+            _ => ValueType::Float64,
+        };
 
+        match (self.as_float64(), exp.as_float64()) {
+            (Some(a), Some(b)) => Ok(Value::from_float(a.powf(b), &value_type)),
             _ =>
                 Err(
                     format!(
@@ -347,4 +1282,8 @@ impl ValueArray {
     pub fn read(&self, index: usize) -> Value {
         self.values[index].clone()
     }
+
+    pub fn replace(&mut self, index: usize, value: Value) {
+        self.values[index] = value;
+    }
 }
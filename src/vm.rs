@@ -1,10 +1,33 @@
 use crate::chunk::Chunk;
-use crate::compiler::Compiler;
+use crate::compiler::{ Compiler, ModuleCache };
+use crate::object::{
+    get_builtin_method,
+    native_len,
+    BoundBuiltin,
+    BoundMethod,
+    Class,
+    Closure,
+    EnumVariant,
+    Globals,
+    Instance,
+    Map,
+    Module,
+    NativeFn,
+    NativeFunction,
+    Upvalue,
+    VariantConstructor,
+    STRINGS_MODULE,
+    STRING_NATIVES,
+};
 use crate::opcodes::OpCode;
-use crate::value::{ Pow, Value, ValueType, Variable };
+use crate::value::{ IntegerDiv, Pow, Value, ValueType, Variable };
 
 use std::collections::hash_map::Entry;
+use std::cell::RefCell;
+use std::cmp::Ordering;
 use std::collections::HashMap;
+use std::path::Path;
+use std::rc::Rc;
 #[cfg(feature = "debug_trace_execution")]
 use std::time::{ SystemTime, UNIX_EPOCH };
 
@@ -16,52 +39,180 @@ pub enum InterpretResult {
     Debug(Value),
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct RuntimeError {
+    message: String,
+}
+
+impl RuntimeError {
+    pub fn new(message: &str) -> Self {
+        Self { message: message.to_string() }
+    }
+
+    pub fn get_message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl From<String> for RuntimeError {
+    fn from(message: String) -> Self {
+        Self { message }
+    }
+}
+
+const FRAMES_MAX: usize = 64;
+
 #[derive(Debug)]
-pub struct VM {
-    chunk: Option<Chunk>,
+pub struct CallFrame {
+    closure: Rc<Closure>,
     ip: usize,
+    slot_offset: usize,
+}
+
+impl CallFrame {
+    pub fn new(closure: Rc<Closure>, slot_offset: usize) -> Self {
+        Self { closure, ip: 0, slot_offset }
+    }
+
+    pub fn get_chunk(&self) -> &Chunk {
+        self.closure.get_function().get_chunk()
+    }
+}
+
+#[derive(Debug)]
+pub struct VM {
+    frames: Vec<CallFrame>,
     stack: Vec<Value>,
+    open_upvalues: Vec<Rc<RefCell<Upvalue>>>,
     had_runtime_error: bool,
-    globals: HashMap<String, Variable>,
+    #[cfg(test)]
+    last_error: Option<String>,
+    type_checking: bool,
+    globals: Globals,
+    natives: HashMap<String, Variable>,
+    module_cache: Rc<RefCell<ModuleCache>>,
+    modules: HashMap<String, Rc<RefCell<Module>>>,
     // stack_top: Value,
 }
 
 impl VM {
     pub fn new() -> Self {
-        Self {
-            chunk: None,
-            ip: 0,
+        let mut vm = Self {
+            frames: Vec::with_capacity(FRAMES_MAX),
             stack: Vec::with_capacity(256),
+            open_upvalues: Vec::new(),
             had_runtime_error: false,
-            globals: HashMap::with_capacity(4),
+            #[cfg(test)]
+            last_error: None,
+            type_checking: false,
+            globals: Rc::new(RefCell::new(HashMap::with_capacity(4))),
+            natives: HashMap::new(),
+            module_cache: Rc::new(RefCell::new(ModuleCache::new())),
+            modules: HashMap::new(),
+        };
+        vm.register_native("len", 1, native_len).unwrap();
+        for (name, arity, function) in STRING_NATIVES {
+            vm.register_native(&format!("{}.{}", STRINGS_MODULE, name), arity, function).unwrap();
+        }
+        vm
+    }
+
+    pub fn set_type_checking(&mut self, enabled: bool) {
+        self.type_checking = enabled;
+    }
+
+    pub fn register_native(&mut self, name: &str, arity: usize, function: NativeFn) -> Result<(), String> {
+        let native = Value::NativeFunction(Rc::new(NativeFunction::new(name.to_string(), arity, function)));
+
+        let Some((module_name, member)) = name.split_once('.') else {
+            if self.natives.contains_key(name) || self.globals.borrow().contains_key(name) {
+                return Err(format!("Cannot register {}, a global with that name already exists", name));
+            }
+            self.define_native_global(name, native);
+            return Ok(());
+        };
+        if member.contains('.') {
+            return Err(format!("Natives can only be nested one module deep, but got {}", name));
         }
+        if module_name == STRINGS_MODULE && arity == 0 {
+            return Err(
+                format!("Cannot register {}, string methods take the string as their first argument", name)
+            );
+        }
+
+        let existing = self.natives
+            .get(module_name)
+            .cloned()
+            .or_else(|| self.globals.borrow().get(module_name).cloned());
+        match existing.map(|variable| variable.get_value()) {
+            Some(Value::Module(module)) => module.borrow_mut().set(member.to_string(), native),
+            Some(_) => {
+                return Err(format!("Cannot register {}, {} is not a module", name, module_name));
+            }
+            None => {
+                let mut module = Module::new(module_name.to_string());
+                module.set(member.to_string(), native);
+                self.define_native_global(module_name, Value::Module(Rc::new(RefCell::new(module))));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn get_builtin(&self, receiver: &Value, name: &str) -> Option<Rc<NativeFunction>> {
+        let Value::String(_) = receiver else {
+            let method = get_builtin_method(&receiver.to_value_type(), name)?;
+            return Some(Rc::new(method.to_native()));
+        };
+
+        let Value::Module(module) = self.natives.get(STRINGS_MODULE)?.get_value() else {
+            return None;
+        };
+        let member = module.borrow().get(name);
+        match member {
+            Some(Value::NativeFunction(native)) => Some(native),
+            _ => None,
+        }
+    }
+
+    fn define_native_global(&mut self, name: &str, value: Value) {
+        let variable = Variable::new(name.to_string(), value, ValueType::Dynamic, false, false);
+        self.module_cache.borrow_mut().declare_prelude(name.to_string(), variable.get_static_type());
+        self.globals.borrow_mut().insert(name.to_string(), variable.clone());
+        self.natives.insert(name.to_string(), variable);
     }
 
     pub fn get_stack(&self) -> &Vec<Value> {
         &self.stack
     }
 
+    #[cfg(test)]
+    pub fn get_last_error(&self) -> Option<&String> {
+        self.last_error.as_ref()
+    }
+
     pub fn reset_stack(&mut self) {
         self.stack.clear();
     }
 
     pub fn free_vm(&mut self) {
-        self.chunk = None;
-        self.ip = 0;
+        self.frames.clear();
+        self.open_upvalues.clear();
         self.stack.clear()
     }
 
-    pub fn init_chunk(&mut self, chunk: Chunk) {
-        self.chunk = Some(chunk);
-        self.ip = 0;
-    }
-
     pub fn free_chunk(&mut self) {
-        self.chunk = None;
-        self.ip = 0;
+        self.frames.clear();
     }
 
     pub fn interpret(&mut self, source: &str) -> InterpretResult {
+        self.interpret_source(source, None)
+    }
+
+    pub fn interpret_file(&mut self, source: &str, path: &Path) -> InterpretResult {
+        self.interpret_source(source, Some(path))
+    }
+
+    fn interpret_source(&mut self, source: &str, path: Option<&Path>) -> InterpretResult {
         #[cfg(feature = "debug_elapsed_time")]
         let secs_start = std::time::SystemTime
             ::now()
@@ -69,16 +220,39 @@ impl VM {
             .unwrap()
             .as_secs_f64();
 
-        let mut chunk = Chunk::new();
-
-        let mut compiler = Compiler::new(source, &mut chunk);
+        self.had_runtime_error = false;
+        #[cfg(test)]
+        {
+            self.last_error = None;
+        }
 
-        if compiler.compile() {
-            self.free_chunk();
-            return InterpretResult::CompileError;
+        let mut compiler = Compiler::new(source);
+        compiler.set_module_cache(self.module_cache.clone());
+        if let Some(path) = path {
+            compiler.set_path(path.canonicalize().unwrap_or(path.to_path_buf()));
+        }
+        for variable in self.globals.borrow().values() {
+            if !variable.is_mutable() {
+                compiler.declare_immutable_global(variable.get_name());
+            }
+        }
+        if self.type_checking {
+            compiler.set_type_checking(true);
+            for variable in self.globals.borrow().values() {
+                compiler.declare_global_type(variable.get_name(), variable.get_static_type());
+            }
         }
 
-        self.init_chunk(chunk);
+        let closure = match compiler.compile() {
+            Some(function) => Rc::new(Closure::new(Rc::new(function), Vec::new(), self.globals.clone())),
+            None => {
+                self.free_chunk();
+                return InterpretResult::CompileError;
+            }
+        };
+
+        self.stack.push(Value::Closure(closure.clone()));
+        self.frames.push(CallFrame::new(closure, 0));
 
         let result = self.run();
 
@@ -98,23 +272,33 @@ impl VM {
         result
     }
 
-    #[cfg(test)]
-    pub fn interpret_chunk(&mut self, chunk: Chunk) -> InterpretResult {
-        self.chunk = Some(chunk);
-        self.ip = 0;
-        self.run()
-    }
-
     pub fn runtime_error(&mut self, message: &str) {
         self.had_runtime_error = true;
 
-        let line: usize = self.chunk
-            .as_ref()
-            .unwrap()
-            .get_line(self.ip - 1);
-        eprintln!("[line {}]: {}", line, message);
+        let report = format!("[{}]: {}", Self::location(self.frame()), message);
+        eprintln!("{}", report);
+        #[cfg(test)]
+        {
+            self.last_error = Some(report);
+        }
+
+        if self.frames.len() > 1 {
+            for frame in self.frames.iter().rev() {
+                eprintln!("    [{}] in {}", Self::location(frame), frame.closure.get_function().get_name());
+            }
+        }
 
         self.reset_stack();
+        self.frames.clear();
+        self.open_upvalues.clear();
+    }
+
+    fn location(frame: &CallFrame) -> String {
+        let line = frame.get_chunk().get_line(frame.ip - 1);
+        match frame.closure.get_function().get_file() {
+            Some(file) => format!("line {} of {}", line, file),
+            None => format!("line {}", line),
+        }
     }
 
     fn run(&mut self) -> InterpretResult {
@@ -130,15 +314,35 @@ impl VM {
                 }
                 println!();
 
-                if self.chunk.is_some() {
-                    self.chunk.as_ref().unwrap().disassemble_instruction(self.ip);
+                if let Some(frame) = self.frames.last() {
+                    frame.get_chunk().disassemble_instruction(frame.ip);
                 }
             }
 
+            if self.had_runtime_error {
+                return InterpretResult::RuntimeError;
+            }
+
             let instruction = self.read_byte().into();
 
             match instruction {
                 OpCode::OpReturn => {
+                    let result = self.stack.pop().unwrap();
+                    let frame = self.frames.pop().unwrap();
+                    self.close_upvalues(frame.slot_offset);
+                    self.stack.truncate(frame.slot_offset);
+
+                    if let Some(exports) = frame.closure.get_function().get_exports() {
+                        let module = self.finish_module(&frame.closure, exports);
+                        self.stack.push(Value::Module(module));
+                        continue;
+                    }
+
+                    if !self.frames.is_empty() {
+                        self.stack.push(result);
+                        continue;
+                    }
+
                     #[cfg(test)]
                     {
                         return InterpretResult::Debug(_debug_result);
@@ -186,7 +390,7 @@ impl VM {
 
                     match -value {
                         Ok(result) => self.stack.push(result),
-                        Err(msg) => eprintln!("{}", msg),
+                        Err(msg) => self.runtime_error(msg.as_str()),
                     }
                 }
                 OpCode::OpNot => {
@@ -196,39 +400,31 @@ impl VM {
                 OpCode::OpAdd => self.binary_op(|a, b| a + b),
                 OpCode::OpSubtract => self.binary_op(|a, b| a - b),
                 OpCode::OpMultiply => self.binary_op(|a, b| a * b),
+                OpCode::OpWrappingAdd => self.binary_op(|a, b| a.wrapping_add(b)),
+                OpCode::OpWrappingSubtract => self.binary_op(|a, b| a.wrapping_sub(b)),
+                OpCode::OpWrappingMultiply => self.binary_op(|a, b| a.wrapping_mul(b)),
+                OpCode::OpSaturatingAdd => self.binary_op(|a, b| a.saturating_add(b)),
+                OpCode::OpSaturatingSubtract => self.binary_op(|a, b| a.saturating_sub(b)),
+                OpCode::OpSaturatingMultiply => self.binary_op(|a, b| a.saturating_mul(b)),
                 OpCode::OpDivide => self.binary_op(|a, b| a / b),
                 OpCode::OpPower => self.binary_op(|a, b| a.pow(b)),
+                OpCode::OpIntegerDivide => self.binary_op(|a, b| a.integer_div(b)),
+                OpCode::OpModulo => self.binary_op(|a, b| a % b),
 
                 OpCode::OpEqualEqual => {
                     let b = self.stack.pop().unwrap();
                     let a = self.stack.pop().unwrap();
-                    self.stack.push(Value::Bool(a == b));
+                    self.stack.push(Value::Bool(a.equals(&b)));
                 }
                 OpCode::OpBangEqual => {
                     let b = self.stack.pop().unwrap();
                     let a = self.stack.pop().unwrap();
-                    self.stack.push(Value::Bool(a != b));
-                }
-                OpCode::OpGreater => {
-                    let b = self.stack.pop().unwrap();
-                    let a = self.stack.pop().unwrap();
-                    self.stack.push(Value::Bool(a > b));
-                }
-                OpCode::OpGreaterEqual => {
-                    let b = self.stack.pop().unwrap();
-                    let a = self.stack.pop().unwrap();
-                    self.stack.push(Value::Bool(a >= b));
-                }
-                OpCode::OpLess => {
-                    let b = self.stack.pop().unwrap();
-                    let a = self.stack.pop().unwrap();
-                    self.stack.push(Value::Bool(a < b));
-                }
-                OpCode::OpLessEqual => {
-                    let b = self.stack.pop().unwrap();
-                    let a = self.stack.pop().unwrap();
-                    self.stack.push(Value::Bool(a <= b));
+                    self.stack.push(Value::Bool(!a.equals(&b)));
                 }
+                OpCode::OpGreater => self.comparison_op(Ordering::is_gt),
+                OpCode::OpGreaterEqual => self.comparison_op(Ordering::is_ge),
+                OpCode::OpLess => self.comparison_op(Ordering::is_lt),
+                OpCode::OpLessEqual => self.comparison_op(Ordering::is_le),
                 OpCode::OpInterpolate => {
                     let b = self.stack.pop().unwrap();
                     let a = self.stack.pop().unwrap();
@@ -253,24 +449,36 @@ impl VM {
                     let constant = self.read_constant();
 
                     if let Value::VariableDefinition(variable_definition) = constant {
+                        let value_type = variable_definition.get_value_type();
                         let value = self.stack.pop().unwrap();
-
-                        let value_type: ValueType;
-                        if variable_definition.get_value_type() == ValueType::Dynamic {
-                            value_type = value.to_value_type();
+                        let value = if variable_definition.is_nullable() {
+                            value.coerce_to_nullable(&value_type)
                         } else {
-                            self.runtime_error("Could not determine the type of the variable");
-                            continue;
-                        }
+                            value.coerce_to(&value_type)
+                        };
+                        let value = match value {
+                            Ok(value) => value,
+                            Err(message) => {
+                                self.runtime_error(
+                                    format!(
+                                        "{} when defining variable '{}'",
+                                        message,
+                                        variable_definition.get_name()
+                                    ).as_str()
+                                );
+                                continue;
+                            }
+                        };
 
                         let variable = Variable::new(
                             variable_definition.get_name(),
                             value,
                             value_type,
+                            variable_definition.is_nullable(),
                             variable_definition.is_mutable()
                         );
 
-                        self.globals.insert(variable_definition.get_name(), variable);
+                        self.frame_globals().borrow_mut().insert(variable_definition.get_name(), variable);
                     } else {
                         self.runtime_error("Expected a variable to define");
                     }
@@ -278,8 +486,12 @@ impl VM {
                 OpCode::OpGetGlobal => {
                     let constant = self.read_constant();
                     if let Value::VariableLookup(variable_name) = constant {
-                        if let Some(value) = self.globals.get(&variable_name) {
-                            self.stack.push(value.get_value());
+                        let value = self.frame_globals()
+                            .borrow()
+                            .get(&variable_name)
+                            .map(|variable| variable.get_value());
+                        if let Some(value) = value {
+                            self.stack.push(value);
                         } else {
                             self.runtime_error("Undefined variable");
                         }
@@ -291,12 +503,17 @@ impl VM {
                     let constant = self.read_constant();
                     if let Value::VariableLookup(variable_name) = constant {
                         let value = self.stack.last().unwrap().clone();
-                        if let Entry::Occupied(mut e) = self.globals.entry(variable_name.clone()) {
-                            let result = (*e.get_mut()).set_value(value);
-                            if !result {
-                                self.runtime_error(
-                                    format!("Cannot reassign immutable variable: {}", variable_name).as_str()
-                                );
+                        let globals = self.frame_globals();
+                        let result = match globals.borrow_mut().entry(variable_name.clone()) {
+                            Entry::Occupied(mut e) => Some((*e.get_mut()).set_value(value)),
+                            Entry::Vacant(_) => None,
+                        };
+                        if let Some(result) = result {
+                            match result {
+                                Ok(value) => {
+                                    *self.stack.last_mut().unwrap() = value;
+                                }
+                                Err(message) => self.runtime_error(message.as_str()),
                             }
                         } else {
                             self.runtime_error(
@@ -307,21 +524,674 @@ impl VM {
                         self.runtime_error("Expected a variable to set");
                     }
                 }
+                OpCode::OpUpdateGlobal => {
+                    let constant = self.read_constant();
+                    let operator = OpCode::from(self.read_byte());
+                    let operand = self.stack.pop().unwrap();
+                    let Value::VariableLookup(variable_name) = constant else {
+                        self.runtime_error("Expected a variable to update");
+                        continue;
+                    };
+                    let operation: fn(Value, Value) -> Result<Value, String> = match operator {
+                        OpCode::OpAdd => |a, b| a + b,
+                        OpCode::OpSubtract => |a, b| a - b,
+                        OpCode::OpMultiply => |a, b| a * b,
+                        OpCode::OpDivide => |a, b| a / b,
+                        OpCode::OpPower => |a, b| a.pow(b),
+                        _ => {
+                            self.runtime_error("Expected an arithmetic operation to update a variable with");
+                            continue;
+                        }
+                    };
+
+                    let globals = self.frame_globals();
+                    let result = match globals.borrow_mut().get_mut(&variable_name) {
+                        Some(variable) => {
+                            operation(variable.get_value(), operand).and_then(|value| variable.set_value(value))
+                        }
+                        None => Err(format!("Undefined variable '{}'", variable_name)),
+                    };
+                    match result {
+                        Ok(value) => self.stack.push(value),
+                        Err(message) => self.runtime_error(message.as_str()),
+                    }
+                }
+                OpCode::OpImport => {
+                    let function = match self.read_constant() {
+                        Value::Function(function) => function,
+                        _ => {
+                            self.runtime_error("Expected a module to import");
+                            continue;
+                        }
+                    };
+
+                    if let Some(module) = self.modules.get(function.get_name()) {
+                        self.stack.push(Value::Module(module.clone()));
+                        continue;
+                    }
+
+                    let globals = Rc::new(RefCell::new(self.natives.clone()));
+                    let closure = Rc::new(Closure::new(function, Vec::new(), globals));
+                    self.stack.push(Value::Closure(closure.clone()));
+                    self.call(closure, 0);
+                }
+                OpCode::OpGetLocal => {
+                    let slot = self.frame().slot_offset + (self.read_byte() as usize);
+                    self.stack.push(self.stack[slot].clone());
+                }
+                OpCode::OpSetLocal => {
+                    let slot = self.frame().slot_offset + (self.read_byte() as usize);
+                    self.stack[slot] = self.stack.last().unwrap().clone();
+                }
+                OpCode::OpJump => {
+                    let offset = self.read_short();
+                    self.frame_mut().ip += offset;
+                }
+                OpCode::OpJumpIfFalse => {
+                    let offset = self.read_short();
+                    if self.stack.last().unwrap().is_falsey() {
+                        self.frame_mut().ip += offset;
+                    }
+                }
+                OpCode::OpJumpIfNull => {
+                    let offset = self.read_short();
+                    if self.stack.last().unwrap() == &Value::Null {
+                        self.frame_mut().ip += offset;
+                    }
+                }
+                OpCode::OpLoop => {
+                    let offset = self.read_short();
+                    self.frame_mut().ip -= offset;
+                }
+                OpCode::OpCall => {
+                    let arg_count = self.read_byte() as usize;
+                    let callee = self.stack[self.stack.len() - 1 - arg_count].clone();
+                    self.call_value(callee, arg_count);
+                }
+                OpCode::OpClosure => {
+                    let function = match self.read_constant() {
+                        Value::Function(function) => function,
+                        _ => {
+                            self.runtime_error("Expected a function to create a closure from");
+                            continue;
+                        }
+                    };
+
+                    let mut upvalues = Vec::with_capacity(function.get_upvalue_count());
+                    for _ in 0..function.get_upvalue_count() {
+                        let is_local = self.read_byte() == 1;
+                        let index = self.read_byte() as usize;
+
+                        if is_local {
+                            let location = self.frame().slot_offset + index;
+                            upvalues.push(self.capture_upvalue(location));
+                        } else {
+                            upvalues.push(self.frame().closure.get_upvalue(index));
+                        }
+                    }
+
+                    let closure = Closure::new(function, upvalues, self.frame_globals().clone());
+                    self.stack.push(Value::Closure(Rc::new(closure)));
+                }
+                OpCode::OpGetUpvalue => {
+                    let index = self.read_byte() as usize;
+                    let upvalue = self.frame().closure.get_upvalue(index);
+
+                    let value = match &*upvalue.borrow() {
+                        Upvalue::Open(location) => self.stack[*location].clone(),
+                        Upvalue::Closed(value) => value.clone(),
+                    };
+                    self.stack.push(value);
+                }
+                OpCode::OpSetUpvalue => {
+                    let index = self.read_byte() as usize;
+                    let upvalue = self.frame().closure.get_upvalue(index);
+                    let value = self.stack.last().unwrap().clone();
+
+                    let mut upvalue = upvalue.borrow_mut();
+                    match &mut *upvalue {
+                        Upvalue::Open(location) => {
+                            self.stack[*location] = value;
+                        }
+                        Upvalue::Closed(closed) => {
+                            *closed = value;
+                        }
+                    }
+                }
+                OpCode::OpCloseUpvalue => {
+                    self.close_upvalues(self.stack.len() - 1);
+                    self.stack.pop();
+                }
+                OpCode::OpClass => {
+                    let name = self.read_constant().to_string();
+                    self.stack.push(Value::Class(Rc::new(RefCell::new(Class::new(name)))));
+                }
+                OpCode::OpMethod => {
+                    let name = self.read_constant().to_string();
+                    let method = self.stack.pop().unwrap();
+
+                    if
+                        let (Value::Class(class), Value::Closure(method)) = (
+                            self.stack.last().unwrap(),
+                            method,
+                        )
+                    {
+                        class.borrow_mut().add_method(name, method);
+                    } else {
+                        self.runtime_error("Expected a method to bind to a class");
+                    }
+                }
+                OpCode::OpInherit => {
+                    let subclass = self.stack.pop().unwrap();
+                    let superclass = self.stack.last().unwrap().clone();
+
+                    match (superclass, subclass) {
+                        (Value::Class(superclass), Value::Class(subclass)) => {
+                            subclass.borrow_mut().inherit(&superclass.borrow());
+                        }
+                        (superclass, _) => {
+                            self.runtime_error(
+                                format!(
+                                    "Superclass must be a class, but got {}",
+                                    superclass.to_type_string()
+                                ).as_str()
+                            );
+                        }
+                    }
+                }
+                OpCode::OpGetSuper => {
+                    let name = self.read_constant().to_string();
+                    let superclass = self.stack.pop().unwrap();
+                    let receiver = self.stack.pop().unwrap();
+
+                    let method = match &superclass {
+                        Value::Class(superclass) => superclass.borrow().get_method(&name),
+                        _ => None,
+                    };
+
+                    match method {
+                        Some(method) => {
+                            let bound_method = BoundMethod::new(receiver, method);
+                            self.stack.push(Value::BoundMethod(Rc::new(bound_method)));
+                        }
+                        None => {
+                            self.runtime_error(
+                                format!("Undefined superclass method '{}'", name).as_str()
+                            );
+                        }
+                    }
+                }
+                OpCode::OpCoerce => {
+                    let Some(value_type) = self.read_value_type() else {
+                        continue;
+                    };
+                    let value = self.stack.pop().unwrap();
+
+                    match value.coerce_to(&value_type) {
+                        Ok(value) => self.stack.push(value),
+                        Err(message) => self.runtime_error(message.as_str()),
+                    }
+                }
+                OpCode::OpCoerceNullable => {
+                    let Some(value_type) = self.read_value_type() else {
+                        continue;
+                    };
+                    let value = self.stack.pop().unwrap();
+
+                    match value.coerce_to_nullable(&value_type) {
+                        Ok(value) => self.stack.push(value),
+                        Err(message) => self.runtime_error(message.as_str()),
+                    }
+                }
+                OpCode::OpCast => {
+                    let Some(value_type) = self.read_value_type() else {
+                        continue;
+                    };
+                    let value = self.stack.pop().unwrap();
+
+                    match value.cast_to(&value_type) {
+                        Ok(value) => self.stack.push(value),
+                        Err(message) => self.runtime_error(message.as_str()),
+                    }
+                }
+                OpCode::OpBuildList => {
+                    let count = self.read_byte() as usize;
+                    let items = self.stack.split_off(self.stack.len() - count);
+                    self.stack.push(Value::new_list(items));
+                }
+                OpCode::OpDuplicate => {
+                    let count = self.read_byte() as usize;
+                    let copies = self.stack[self.stack.len() - count..].to_vec();
+                    self.stack.extend(copies);
+                }
+                OpCode::OpMatchTuple => {
+                    let length = self.read_byte() as usize;
+                    let value = self.stack.pop().unwrap();
+                    let is_match = matches!(&value, Value::Tuple(tuple) if tuple.len() == length);
+                    self.stack.push(Value::Bool(is_match));
+                }
+                OpCode::OpMatchList => {
+                    let length = self.read_byte() as usize;
+                    let value = self.stack.pop().unwrap();
+                    let is_match = matches!(&value, Value::List(list) if list.borrow().len() == length);
+                    self.stack.push(Value::Bool(is_match));
+                }
+                OpCode::OpMatchVariant => {
+                    let name = self.read_constant().to_string();
+                    let count = self.read_byte() as usize;
+                    let enumeration = self.stack.pop().unwrap();
+                    let value = self.stack.pop().unwrap();
+
+                    let Value::Enum(enumeration) = enumeration else {
+                        self.runtime_error(
+                            format!(
+                                "Can only match variants of enums, but got {:?}",
+                                enumeration.to_type_string()
+                            ).as_str()
+                        );
+                        continue;
+                    };
+
+                    let Some(tag) = enumeration.get_tag(&name) else {
+                        self.runtime_error(
+                            format!("Enum {} has no variant '{}'", enumeration.get_name(), name).as_str()
+                        );
+                        continue;
+                    };
+
+                    let arity = enumeration.get_variant(tag).arity();
+                    if arity != count {
+                        self.runtime_error(
+                            format!(
+                                "Variant {}.{} holds {} values but the pattern has {}",
+                                enumeration.get_name(),
+                                name,
+                                arity,
+                                count
+                            ).as_str()
+                        );
+                        continue;
+                    }
+
+                    let is_match = matches!(
+                        &value,
+                        Value::EnumVariant(variant) if
+                            Rc::ptr_eq(variant.get_enum(), &enumeration) && variant.get_tag() == tag
+                    );
+                    self.stack.push(Value::Bool(is_match));
+                }
+                OpCode::OpCheckEnum => {
+                    let enumeration = self.stack.pop().unwrap();
+                    let value = self.stack.last().unwrap();
+
+                    let Value::Enum(enumeration) = enumeration else {
+                        let message = format!(
+                            "Can only check values against enums, but got {:?}",
+                            enumeration.to_type_string()
+                        );
+                        self.runtime_error(message.as_str());
+                        continue;
+                    };
+
+                    // Enums are compared by identity, another enum of the same name is a different type
+                    let is_match = matches!(
+                        value,
+                        Value::EnumVariant(variant) if Rc::ptr_eq(variant.get_enum(), &enumeration)
+                    );
+                    if !is_match {
+                        let message = format!(
+                            "Expected type {:?} but got {:?}",
+                            enumeration.get_name(),
+                            value.to_type_string()
+                        );
+                        self.runtime_error(message.as_str());
+                    }
+                }
+                OpCode::OpBuildTuple => {
+                    let count = self.read_byte() as usize;
+                    let items = self.stack.split_off(self.stack.len() - count);
+                    self.stack.push(Value::new_tuple(items));
+                }
+                OpCode::OpUnpack => {
+                    let count = self.read_byte() as usize;
+                    let value = self.stack.pop().unwrap();
+
+                    match value.unpack(count) {
+                        Ok(items) => self.stack.extend(items),
+                        Err(message) => self.runtime_error(message.as_str()),
+                    }
+                }
+                OpCode::OpBuildMap => {
+                    let count = self.read_byte() as usize;
+                    let entries = self.stack.split_off(self.stack.len() - count * 2);
+
+                    let mut map = Map::new();
+                    for entry in entries.chunks(2) {
+                        if let Err(message) = map.insert(entry[0].clone(), entry[1].clone()) {
+                            self.runtime_error(message.as_str());
+                            break;
+                        }
+                    }
+                    self.stack.push(Value::Map(Rc::new(RefCell::new(map))));
+                }
+                OpCode::OpIterate => {
+                    let collection = self.stack.pop().unwrap();
+
+                    match collection.to_iteration_list() {
+                        Ok(items) => self.stack.push(items),
+                        Err(message) => self.runtime_error(message.as_str()),
+                    }
+                }
+                OpCode::OpGetIndex => {
+                    let index = self.stack.pop().unwrap();
+                    let target = self.stack.pop().unwrap();
+
+                    match target.get_index(&index) {
+                        Ok(value) => self.stack.push(value),
+                        Err(message) => self.runtime_error(message.as_str()),
+                    }
+                }
+                OpCode::OpSetIndex => {
+                    let value = self.stack.pop().unwrap();
+                    let index = self.stack.pop().unwrap();
+                    let target = self.stack.pop().unwrap();
+
+                    match target.set_index(&index, value.clone()) {
+                        Ok(()) => self.stack.push(value),
+                        Err(message) => self.runtime_error(message.as_str()),
+                    }
+                }
+                OpCode::OpGetProperty => {
+                    let name = self.read_constant().to_string();
+                    let receiver = self.stack.pop().unwrap();
+
+                    let instance = match &receiver {
+                        Value::Instance(instance) => instance.clone(),
+                        Value::Enum(enumeration) => {
+                            match enumeration.get_tag(&name) {
+                                Some(tag) if enumeration.get_variant(tag).arity() == 0 => {
+                                    match EnumVariant::new(enumeration.clone(), tag, Vec::new()) {
+                                        Ok(variant) => {
+                                            self.stack.push(Value::EnumVariant(Rc::new(variant)));
+                                        }
+                                        Err(message) => self.runtime_error(message.as_str()),
+                                    }
+                                }
+                                Some(tag) => {
+                                    let constructor = VariantConstructor::new(enumeration.clone(), tag);
+                                    self.stack.push(Value::VariantConstructor(Rc::new(constructor)));
+                                }
+                                None => {
+                                    self.runtime_error(
+                                        format!(
+                                            "Enum {} has no variant '{}'",
+                                            enumeration.get_name(),
+                                            name
+                                        ).as_str()
+                                    );
+                                }
+                            }
+                            continue;
+                        }
+                        Value::Module(module) => {
+                            let member = module.borrow().get(&name);
+                            match member {
+                                Some(value) => self.stack.push(value),
+                                None => {
+                                    self.runtime_error(
+                                        format!(
+                                            "Module {} has no member '{}'",
+                                            module.borrow().get_name(),
+                                            name
+                                        ).as_str()
+                                    );
+                                }
+                            }
+                            continue;
+                        }
+                        Value::List(_) | Value::Map(_) | Value::Tuple(_) | Value::String(_) => {
+                            match self.get_builtin(&receiver, &name) {
+                                Some(native) => {
+                                    let bound = BoundBuiltin::new(receiver.clone(), native);
+                                    self.stack.push(Value::BoundBuiltin(Rc::new(bound)));
+                                }
+                                None => {
+                                    self.runtime_error(
+                                        format!(
+                                            "{} has no method '{}'",
+                                            receiver.to_type_string(),
+                                            name
+                                        ).as_str()
+                                    );
+                                }
+                            }
+                            continue;
+                        }
+                        _ => {
+                            self.runtime_error(
+                                format!(
+                                    "Only instances have properties, but got {}",
+                                    receiver.to_type_string()
+                                ).as_str()
+                            );
+                            continue;
+                        }
+                    };
+
+                    if let Some(value) = instance.borrow().get_field(&name) {
+                        self.stack.push(value);
+                        continue;
+                    }
+
+                    let method = instance.borrow().get_class().borrow().get_method(&name);
+                    match method {
+                        Some(method) => {
+                            let bound_method = BoundMethod::new(receiver, method);
+                            self.stack.push(Value::BoundMethod(Rc::new(bound_method)));
+                        }
+                        None => {
+                            self.runtime_error(format!("Undefined property '{}'", name).as_str());
+                        }
+                    }
+                }
+                OpCode::OpSetProperty => {
+                    let name = self.read_constant().to_string();
+                    let value = self.stack.pop().unwrap();
+                    let receiver = self.stack.pop().unwrap();
+
+                    if let Value::Instance(instance) = receiver {
+                        instance.borrow_mut().set_field(name, value.clone());
+                        self.stack.push(value);
+                    } else if let Value::Module(module) = receiver {
+                        self.runtime_error(
+                            format!(
+                                "Cannot set '{}', members of module '{}' are read-only",
+                                name,
+                                module.borrow().get_name()
+                            ).as_str()
+                        );
+                    } else {
+                        self.runtime_error(
+                            format!(
+                                "Only instances have fields, but got {}",
+                                receiver.to_type_string()
+                            ).as_str()
+                        );
+                    }
+                }
             }
         }
     }
 
-    fn read_byte(&mut self) -> u8 {
-        self.ip += 1;
-        let ip = self.ip;
-        if let Some(chunk) = &self.chunk {
-            if let Some(code) = chunk.get_code().get(ip - 1) {
-                *code
-            } else {
-                self.runtime_error("Could not get operation (OPCODE)");
-                0
+    fn frame(&self) -> &CallFrame {
+        self.frames.last().unwrap()
+    }
+
+    fn finish_module(&mut self, closure: &Closure, exports: &[String]) -> Rc<RefCell<Module>> {
+        let path = closure.get_function().get_name();
+        let name = Path::new(path)
+            .file_stem()
+            .map_or(path.clone(), |stem| stem.to_string_lossy().to_string());
+
+        let exports = exports.iter().cloned().collect();
+        let module = Module::with_globals(name, closure.get_globals().clone(), exports);
+
+        let module = Rc::new(RefCell::new(module));
+        self.modules.insert(path.clone(), module.clone());
+        module
+    }
+
+    fn frame_globals(&self) -> &Globals {
+        self.frame().closure.get_globals()
+    }
+
+    fn frame_mut(&mut self) -> &mut CallFrame {
+        self.frames.last_mut().unwrap()
+    }
+
+    fn call_value(&mut self, callee: Value, arg_count: usize) {
+        let callee_slot = self.stack.len() - arg_count - 1;
+
+        match callee {
+            Value::Closure(closure) => self.call(closure, arg_count),
+            Value::BoundMethod(bound_method) => {
+                self.stack[callee_slot] = bound_method.get_receiver().clone();
+                self.call(bound_method.get_method().clone(), arg_count);
+            }
+            Value::BoundBuiltin(bound_builtin) => {
+                let arity = bound_builtin.get_arity();
+                if arg_count != arity {
+                    self.runtime_error(
+                        format!(
+                            "Expected {} arguments but got {} when calling {}",
+                            arity,
+                            arg_count,
+                            bound_builtin.get_native().get_name()
+                        ).as_str()
+                    );
+                    return;
+                }
+
+                // The receiver takes the place of the callee as the first argument of the native
+                self.stack[callee_slot] = bound_builtin.get_receiver().clone();
+                let args = self.stack.split_off(callee_slot);
+
+                match bound_builtin.get_native().call(self, &args) {
+                    Ok(result) => self.stack.push(result),
+                    Err(error) => self.runtime_error(error.get_message()),
+                }
+            }
+            Value::NativeFunction(native) => {
+                if arg_count != native.get_arity() {
+                    self.runtime_error(
+                        format!(
+                            "Expected {} arguments but got {} when calling {}",
+                            native.get_arity(),
+                            arg_count,
+                            native.get_name()
+                        ).as_str()
+                    );
+                    return;
+                }
+
+                let args = self.stack.split_off(callee_slot + 1);
+                self.stack.pop();
+
+                match native.call(self, &args) {
+                    Ok(result) => self.stack.push(result),
+                    Err(error) => self.runtime_error(error.get_message()),
+                }
+            }
+            Value::Class(class) => {
+                let instance = Instance::new(class.clone());
+                self.stack[callee_slot] = Value::Instance(Rc::new(RefCell::new(instance)));
+
+                if let Some(initializer) = class.borrow().get_method("init") {
+                    self.call(initializer, arg_count);
+                } else if arg_count != 0 {
+                    self.runtime_error(
+                        format!("Expected 0 arguments but got {}", arg_count).as_str()
+                    );
+                }
+            }
+            Value::VariantConstructor(constructor) => {
+                let args = self.stack.split_off(callee_slot + 1);
+                self.stack.pop();
+
+                match constructor.construct(args) {
+                    Ok(variant) => self.stack.push(Value::EnumVariant(Rc::new(variant))),
+                    Err(message) => self.runtime_error(message.as_str()),
+                }
+            }
+            _ => self.runtime_error("Can only call functions and classes"),
+        }
+    }
+
+    fn call(&mut self, closure: Rc<Closure>, arg_count: usize) {
+        let function = closure.get_function();
+
+        if arg_count != function.get_arity() {
+            self.runtime_error(
+                format!(
+                    "Expected {} arguments but got {} when calling {}",
+                    function.get_arity(),
+                    arg_count,
+                    function.get_name()
+                ).as_str()
+            );
+            return;
+        }
+
+        if self.frames.len() == FRAMES_MAX {
+            self.runtime_error("Stack overflow");
+            return;
+        }
+
+        let slot_offset = self.stack.len() - arg_count - 1;
+        self.frames.push(CallFrame::new(closure, slot_offset));
+    }
+
+    fn capture_upvalue(&mut self, location: usize) -> Rc<RefCell<Upvalue>> {
+        for upvalue in &self.open_upvalues {
+            if let Upvalue::Open(open_location) = *upvalue.borrow() {
+                if open_location == location {
+                    return upvalue.clone();
+                }
+            }
+        }
+
+        let upvalue = Rc::new(RefCell::new(Upvalue::Open(location)));
+        self.open_upvalues.push(upvalue.clone());
+        upvalue
+    }
+
+    fn close_upvalues(&mut self, last: usize) {
+        let stack = &self.stack;
+
+        self.open_upvalues.retain(|upvalue| {
+            let location = match *upvalue.borrow() {
+                Upvalue::Open(location) => location,
+                Upvalue::Closed(_) => {
+                    return false;
+                }
+            };
+
+            if location < last {
+                return true;
             }
+
+            *upvalue.borrow_mut() = Upvalue::Closed(stack[location].clone());
+            false
+        });
+    }
+
+    fn read_byte(&mut self) -> u8 {
+        let frame = self.frames.last_mut().unwrap();
+        frame.ip += 1;
+        if let Some(code) = frame.get_chunk().get_code().get(frame.ip - 1) {
+            *code
         } else {
+            self.runtime_error("Could not get operation (OPCODE)");
             0
         }
     }
@@ -333,19 +1203,42 @@ impl VM {
         result
     }
 
+    fn read_short(&mut self) -> usize {
+        self.read_bytes(2)
+    }
+
+    fn read_value_type(&mut self) -> Option<ValueType> {
+        match ValueType::try_from(self.read_byte()) {
+            Ok(value_type) => Some(value_type),
+            Err(message) => {
+                self.runtime_error(message.as_str());
+                None
+            }
+        }
+    }
+
     fn read_constant(&mut self) -> Value {
         let byte = self.read_byte().into();
-        self.chunk.as_ref().unwrap().read_constant(byte)
+        self.frame().get_chunk().read_constant(byte)
     }
 
     fn read_long_constant(&mut self) -> Value {
         let bytes = self.read_bytes(3);
-        self.chunk
-            .as_ref()
-            .unwrap()
+        self.frame()
+            .get_chunk()
             .read_constant(bytes as u16)
     }
 
+    fn comparison_op(&mut self, test: fn(Ordering) -> bool) {
+        let b = self.stack.pop().unwrap();
+        let a = self.stack.pop().unwrap();
+
+        match a.compare(&b) {
+            Ok(ordering) => self.stack.push(Value::Bool(ordering.is_some_and(test))),
+            Err(msg) => self.runtime_error(msg.as_str()),
+        }
+    }
+
     fn binary_op(&mut self, op: fn(a: Value, b: Value) -> Result<Value, String>) {
         let b = match self.stack.pop() {
             Some(b) => b,